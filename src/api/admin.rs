@@ -0,0 +1,34 @@
+use crate::api::hooks::Hooks;
+
+/// The [Admin] struct provides methods that require the authenticated user to be an
+/// administrator of the instance.
+pub struct Admin;
+
+impl Admin {
+    /// Methods for managing the instance's system and default webhooks.
+    /// System webhooks are delivered for events in all repositories, default webhooks are added
+    /// to every new repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_admin_hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .admin()
+    ///     .hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn hooks(&self) -> Hooks {
+        Hooks {
+            path: "admin/hooks".to_string(),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::hooks::{Hook, HookContentType, HookType},
+    Executor,
+};
+
+/// Options for creating a webhook.
+/// The type and the URL are required, the payload is sent as JSON unless a different
+/// [content type](CreateHookBuilder::content_type) is set.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateHookBuilder {
+    #[skip]
+    #[serde(skip)]
+    path: String,
+    #[skip]
+    r#type: HookType,
+    #[skip]
+    config: HashMap<String, String>,
+    /// The events to deliver the webhook for, like `push` or `pull_request`.
+    /// Defaults to `push` only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<String>>,
+    /// A glob pattern of the branches to deliver push, branch creation and branch deletion
+    /// events for, like `{main,release/*}`. Defaults to all branches.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch_filter: Option<String>,
+    /// The `Authorization` header to send with each delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authorization_header: Option<String>,
+    /// Whether the webhook is delivered. Defaults to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+impl CreateHookBuilder {
+    pub(crate) fn new(path: String, r#type: HookType, url: impl ToString) -> Self {
+        let config = HashMap::from([
+            ("url".to_string(), url.to_string()),
+            (
+                "content_type".to_string(),
+                HookContentType::Json.as_str().to_string(),
+            ),
+        ]);
+        Self {
+            path,
+            r#type,
+            config,
+            events: None,
+            branch_filter: None,
+            authorization_header: None,
+            active: Some(true),
+        }
+    }
+    /// The format the payload is sent in.
+    pub fn content_type(mut self, content_type: HookContentType) -> Self {
+        self.config.insert(
+            "content_type".to_string(),
+            content_type.as_str().to_string(),
+        );
+        self
+    }
+    /// The secret used to sign the payload. The signature is sent in the `X-Gitea-Signature`
+    /// header.
+    pub fn secret(mut self, secret: impl ToString) -> Self {
+        self.config.insert("secret".to_string(), secret.to_string());
+        self
+    }
+    /// Sets a type-specific configuration value, like `channel` or `username` for Slack
+    /// webhooks.
+    pub fn config(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.config.insert(key.to_string(), value.to_string());
+        self
+    }
+    /// Send the request to create the webhook.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Hook>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.post(&self.path).json(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
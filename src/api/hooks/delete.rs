@@ -0,0 +1,24 @@
+use crate::{error::Result, Executor};
+
+#[derive(Debug, Clone)]
+pub struct DeleteHookBuilder {
+    path: String,
+    id: i64,
+}
+
+impl DeleteHookBuilder {
+    pub(crate) fn new(path: String, id: i64) -> Self {
+        Self { path, id }
+    }
+    /// Send the request to delete the webhook.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client
+                .delete(format!("{}/{}", self.path, self.id))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::hooks::{Hook, HookContentType},
+    Executor,
+};
+
+/// Options for editing a webhook.
+/// Fields that are not set are left unchanged.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditHookBuilder {
+    #[skip]
+    #[serde(skip)]
+    path: String,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    #[skip]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: Option<HashMap<String, String>>,
+    /// The events to deliver the webhook for, like `push` or `pull_request`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<String>>,
+    /// A glob pattern of the branches to deliver push, branch creation and branch deletion
+    /// events for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch_filter: Option<String>,
+    /// The `Authorization` header to send with each delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authorization_header: Option<String>,
+    /// Whether the webhook is delivered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+impl EditHookBuilder {
+    pub(crate) fn new(path: String, id: i64) -> Self {
+        Self {
+            path,
+            id,
+            config: None,
+            events: None,
+            branch_filter: None,
+            authorization_header: None,
+            active: None,
+        }
+    }
+    /// The URL the webhook is delivered to.
+    pub fn url(self, url: impl ToString) -> Self {
+        self.config("url", url)
+    }
+    /// The format the payload is sent in.
+    pub fn content_type(self, content_type: HookContentType) -> Self {
+        self.config("content_type", content_type.as_str())
+    }
+    /// The secret used to sign the payload.
+    pub fn secret(self, secret: impl ToString) -> Self {
+        self.config("secret", secret)
+    }
+    /// Sets a type-specific configuration value, like `channel` or `username` for Slack
+    /// webhooks.
+    pub fn config(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.config
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+    /// Send the request to edit the webhook.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Hook>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client
+                .patch(format!("{}/{}", self.path, self.id))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{error::Result, model::hooks::Hook, Executor};
+
+#[derive(Debug, Clone)]
+pub struct GetHookBuilder {
+    path: String,
+    id: i64,
+}
+
+impl GetHookBuilder {
+    pub(crate) fn new(path: String, id: i64) -> Self {
+        Self { path, id }
+    }
+    /// Send the request to get the webhook.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Hook>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(format!("{}/{}", self.path, self.id)).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
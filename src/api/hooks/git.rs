@@ -0,0 +1,137 @@
+use serde::Serialize;
+
+use crate::{error::Result, model::hooks::GitHook, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct ListGitHooksBuilder {
+    owner: String,
+    repo: String,
+}
+
+impl ListGitHooksBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+    /// Send the request to list the git hooks.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<GitHook>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/hooks/git"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetGitHookBuilder {
+    owner: String,
+    repo: String,
+    name: String,
+}
+
+impl GetGitHookBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            name: name.to_string(),
+        }
+    }
+    /// Send the request to get the git hook.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<GitHook>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let name = segment(&self.name);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/hooks/git/{name}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+/// Sets the script of a git hook.
+#[derive(Debug, Clone, Serialize)]
+pub struct EditGitHookBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    name: String,
+    /// The script to run.
+    content: String,
+}
+
+impl EditGitHookBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        name: impl ToString,
+        content: impl ToString,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            name: name.to_string(),
+            content: content.to_string(),
+        }
+    }
+    /// Send the request to edit the git hook.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<GitHook>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let name = segment(&self.name);
+            let req = client
+                .patch(format!("repos/{owner}/{repo}/hooks/git/{name}"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteGitHookBuilder {
+    owner: String,
+    repo: String,
+    name: String,
+}
+
+impl DeleteGitHookBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            name: name.to_string(),
+        }
+    }
+    /// Send the request to delete the git hook's script.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let name = segment(&self.name);
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/hooks/git/{name}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
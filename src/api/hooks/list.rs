@@ -0,0 +1,59 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::hooks::Hook, pagination::Paginated, Executor};
+
+/// Options for listing webhooks.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListHooksBuilder {
+    #[skip]
+    #[serde(skip)]
+    path: String,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListHooksBuilder {
+    pub(crate) fn new(path: String) -> Self {
+        Self {
+            path,
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the webhooks.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Hook>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListHooksBuilder {
+    type Item = Hook;
+
+    fn path(&self) -> String {
+        self.path.clone()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
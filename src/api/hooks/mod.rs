@@ -0,0 +1,243 @@
+use crate::model::hooks::HookType;
+
+pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod git;
+pub mod list;
+pub mod test;
+
+/// The [Hooks] struct provides methods for managing webhooks.
+/// It is used for the webhooks of repositories ([Repos::hooks](crate::api::repos::Repos::hooks)),
+/// organizations ([Orgs::hooks](crate::api::orgs::Orgs::hooks)), the authenticated user
+/// ([User::hooks](crate::api::user::User::hooks)) and the whole instance
+/// ([Admin::hooks](crate::api::admin::Admin::hooks)).
+pub struct Hooks {
+    /// The API path of the webhooks, like `repos/{owner}/{repo}/hooks`.
+    pub(crate) path: String,
+}
+
+impl Hooks {
+    /// Lists the webhooks.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> list::ListHooksBuilder {
+        list::ListHooksBuilder::new(self.path.clone())
+    }
+
+    /// Gets a webhook by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hook = client
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, id: i64) -> get::GetHookBuilder {
+        get::GetHookBuilder::new(self.path.clone(), id)
+    }
+
+    /// Creates a webhook of the given type that is delivered to the given URL.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::hooks::HookType};
+    /// # async fn create_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hook = client
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .create(HookType::Gitea, "https://ci.example.com/hooks/gitea")
+    ///     .secret("webhook-secret")
+    ///     .events(vec!["push".to_string(), "pull_request".to_string()])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create(&self, r#type: HookType, url: impl ToString) -> create::CreateHookBuilder {
+        create::CreateHookBuilder::new(self.path.clone(), r#type, url)
+    }
+
+    /// Edits a webhook.
+    /// Fields that are not set are left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hook = client
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .edit(1)
+    ///     .active(false)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self, id: i64) -> edit::EditHookBuilder {
+        edit::EditHookBuilder::new(self.path.clone(), id)
+    }
+
+    /// Deletes a webhook.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .delete(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, id: i64) -> delete::DeleteHookBuilder {
+        delete::DeleteHookBuilder::new(self.path.clone(), id)
+    }
+}
+
+/// The [GitHooks] struct provides methods for managing a repository's server-side git hooks.
+/// Git hooks are scripts that run on the Gitea server, for example to reject pushes.
+/// Managing them requires the user to be allowed to edit git hooks.
+pub struct GitHooks {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl GitHooks {
+    /// Lists the repository's git hooks.
+    /// All hooks Gitea supports are listed, hooks without content are inactive.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_git_hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .repos("owner", "repo")
+    ///     .git_hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> git::ListGitHooksBuilder {
+        git::ListGitHooksBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Gets a git hook by its name, like `pre-receive`.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_git_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hook = client
+    ///     .repos("owner", "repo")
+    ///     .git_hooks()
+    ///     .get("pre-receive")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, name: impl ToString) -> git::GetGitHookBuilder {
+        git::GetGitHookBuilder::new(&self.owner, &self.repo, name)
+    }
+
+    /// Sets the script of a git hook.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_git_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hook = client
+    ///     .repos("owner", "repo")
+    ///     .git_hooks()
+    ///     .edit("pre-receive", "#!/bin/sh\nexit 0\n")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self, name: impl ToString, content: impl ToString) -> git::EditGitHookBuilder {
+        git::EditGitHookBuilder::new(&self.owner, &self.repo, name, content)
+    }
+
+    /// Deletes the script of a git hook, which deactivates it.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_git_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .git_hooks()
+    ///     .delete("pre-receive")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, name: impl ToString) -> git::DeleteGitHookBuilder {
+        git::DeleteGitHookBuilder::new(&self.owner, &self.repo, name)
+    }
+}
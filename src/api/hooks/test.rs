@@ -0,0 +1,51 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, path::segment, Executor};
+
+/// Triggers a test delivery of a repository webhook.
+/// The delivery uses a push event payload for the latest commit.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct TestHookBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// The branch or tag to use the latest commit of.
+    /// Defaults to the repository's default branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[build_it(rename = "refs")]
+    r#ref: Option<String>,
+}
+
+impl TestHookBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            r#ref: None,
+        }
+    }
+    /// Send the request to trigger the test delivery.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .post(format!("repos/{owner}/{repo}/hooks/{id}/tests"))
+                .query(self)
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Comment, Client};
+use crate::{error::Result, model::issues::Comment, path::segment, Executor};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 pub struct CreateCommentBuilder {
@@ -33,15 +33,18 @@ impl CreateCommentBuilder {
     }
 
     /// Sends the request to create a comment on an issue.
-    pub async fn send(self, client: &Client) -> Result<Comment> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let issue = self.issue;
-        let req = client
-            .post(format!("repos/{owner}/{repo}/issues/{issue}/comments"))
-            .json(&self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(self, executor: &'a E) -> E::Output<'a, Result<Comment>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let issue = self.issue;
+            let req = client
+                .post(format!("repos/{owner}/{repo}/issues/{issue}/comments"))
+                .json(&self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
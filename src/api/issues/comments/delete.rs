@@ -1,4 +1,4 @@
-use crate::{error::Result, Client};
+use crate::{error::Result, path::segment, Executor};
 
 #[derive(Debug, Clone)]
 pub struct DeleteCommentBuilder {
@@ -17,14 +17,17 @@ impl DeleteCommentBuilder {
     }
 
     /// Sends the request to delete a comment.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let req = client
-            .delete(format!(
-                "repos/{}/{}/issues/comments/{}",
-                self.owner, self.repo, self.comment
-            ))
-            .build()?;
-        let _ = client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let comment = self.comment;
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/issues/comments/{comment}"))
+                .build()?;
+            let _ = client.make_request(req).await?;
+            Ok(())
+        })
     }
 }
@@ -1,7 +1,7 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Comment, Client};
+use crate::{error::Result, model::issues::Comment, path::segment, Executor};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 pub struct EditCommentBuilder {
@@ -41,18 +41,21 @@ impl EditCommentBuilder {
     /// NOTE: This is the only endpoint which returns an option. That's because the Gitea API
     /// decided - in their infinite wisdom - to sometimes return a 204 No Content status code
     /// when editing a comment, which means there's no response body to parse.
-    pub async fn send(self, client: &Client) -> Result<Option<Comment>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let comment = self.comment;
-        let req = client
-            .patch(format!("repos/{owner}/{repo}/issues/comments/{comment}"))
-            .json(&self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        if res.status() == 204 {
-            return Ok(None);
-        }
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(self, executor: &'a E) -> E::Output<'a, Result<Option<Comment>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let comment = self.comment;
+            let req = client
+                .patch(format!("repos/{owner}/{repo}/issues/comments/{comment}"))
+                .json(&self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            if res.status() == 204 {
+                return Ok(None);
+            }
+            client.parse_response(res).await
+        })
     }
 }
@@ -1,4 +1,4 @@
-use crate::{error::Result, model::issues::Comment, Client};
+use crate::{error::Result, model::issues::Comment, path::segment, Executor};
 
 #[derive(Debug, Clone)]
 pub struct GetCommentBuilder {
@@ -17,14 +17,17 @@ impl GetCommentBuilder {
     }
 
     /// Sends the request to get a comment on an issue.
-    pub async fn send(self, client: &Client) -> Result<Comment> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let comment = self.comment;
-        let req = client
-            .get(format!("repos/{owner}/{repo}/issues/comments/{comment}"))
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(self, executor: &'a E) -> E::Output<'a, Result<Comment>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let comment = self.comment;
+            let req = client
+                .get(format!("repos/{owner}/{repo}/issues/comments/{comment}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
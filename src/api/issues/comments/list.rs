@@ -1,7 +1,9 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Comment, Client};
+use crate::{
+    error::Result, model::issues::Comment, pagination::Paginated, path::segment, Executor,
+};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 #[build_it(into)]
@@ -59,15 +61,13 @@ impl ListAllCommentsBuilder {
     }
 
     /// Sends the request to list a repository's comments.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Comment>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .get(format!("repos/{owner}/{repo}/issues/comments"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Comment>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -85,15 +85,57 @@ impl ListCommentsBuilder {
     }
 
     /// Sends the request to list an issue's comments.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Comment>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Comment>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListAllCommentsBuilder {
+    type Item = Comment;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        format!("repos/{owner}/{repo}/issues/comments")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+impl Paginated for ListCommentsBuilder {
+    type Item = Comment;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
         let issue = self.issue;
-        let req = client
-            .get(format!("repos/{owner}/{repo}/issues/{issue}/comments"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        format!("repos/{owner}/{repo}/issues/{issue}/comments")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
@@ -1,7 +1,12 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Issue};
+use crate::{
+    error::Result,
+    model::{issues::Issue, Timestamp},
+    path::segment,
+    Executor,
+};
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -22,7 +27,7 @@ pub struct CreateIssueBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub closed: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub due_date: Option<String>,
+    pub due_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<Vec<i64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,15 +54,18 @@ impl CreateIssueBuilder {
     }
 
     /// Send the request to create the issue.
-    pub async fn send(&self, client: &crate::Client) -> Result<Issue> {
-        // send the request
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .post(format!("repos/{owner}/{repo}/issues"))
-            .json(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Issue>> {
+        let client = executor.client();
+        executor.execute(async move {
+            // send the request
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/issues"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
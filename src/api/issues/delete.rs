@@ -1,4 +1,6 @@
 use crate::error::Result;
+use crate::path::segment;
+use crate::Executor;
 
 pub struct DeleteIssueBuilder {
     owner: String,
@@ -14,16 +16,17 @@ impl DeleteIssueBuilder {
             issue_number,
         }
     }
-    pub async fn send(&self, client: &crate::Client) -> Result<()> {
-        let DeleteIssueBuilder {
-            owner,
-            repo,
-            issue_number,
-        } = self;
-        let req = client
-            .delete(format!("repos/{owner}/{repo}/issues/{issue_number}",))
-            .build()?;
-        client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let issue_number = self.issue_number;
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/issues/{issue_number}",))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
     }
 }
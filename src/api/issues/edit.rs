@@ -1,7 +1,12 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::issues::Issue};
+use crate::{
+    error::Result,
+    model::{issues::Issue, Timestamp},
+    path::segment,
+    Executor,
+};
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -21,7 +26,7 @@ pub struct EditIssueBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub due_date: Option<String>,
+    pub due_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub milestone: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -32,8 +37,7 @@ pub struct EditIssueBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unset_due_date: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    // TODO: Make this a DateTime<Utc>
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
 }
 
 impl EditIssueBuilder {
@@ -54,16 +58,19 @@ impl EditIssueBuilder {
     }
 
     /// Send the request to edit the issue.
-    pub async fn send(&self, client: &crate::Client) -> Result<Issue> {
-        // send the request
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let index = &self.issue_number;
-        let req = client
-            .patch(format!("repos/{owner}/{repo}/issues/{index}"))
-            .json(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Issue>> {
+        let client = executor.client();
+        executor.execute(async move {
+            // send the request
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = &self.issue_number;
+            let req = client
+                .patch(format!("repos/{owner}/{repo}/issues/{index}"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
@@ -1,5 +1,7 @@
 use crate::error::Result;
 use crate::model::issues::Issue;
+use crate::path::segment;
+use crate::Executor;
 
 #[derive(Debug, Clone)]
 pub struct GetIssueBuilder {
@@ -17,14 +19,17 @@ impl GetIssueBuilder {
         }
     }
     /// Send the request to get the issues.
-    pub async fn send(&self, client: &crate::Client) -> Result<Issue> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let index = &self.issue_number;
-        let req = client
-            .get(format!("repos/{owner}/{repo}/issues/{index}"))
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Issue>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = &self.issue_number;
+            let req = client
+                .get(format!("repos/{owner}/{repo}/issues/{index}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
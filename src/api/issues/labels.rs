@@ -0,0 +1,191 @@
+use serde::Serialize;
+
+use crate::{error::Result, model::issues::Label, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct ListIssueLabelsBuilder {
+    owner: String,
+    repo: String,
+    issue_number: i64,
+}
+
+impl ListIssueLabelsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue_number: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue_number,
+        }
+    }
+    /// Send the request to list the issue's labels.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Label>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.issue_number;
+            let req = client
+                .get(format!("repos/{owner}/{repo}/issues/{index}/labels"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+/// Adds labels to an issue, keeping its other labels.
+#[derive(Debug, Clone, Serialize)]
+pub struct AddIssueLabelsBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    issue_number: i64,
+    /// The IDs of the labels to add.
+    labels: Vec<i64>,
+}
+
+/// Replaces all labels of an issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplaceIssueLabelsBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    issue_number: i64,
+    /// The IDs of the issue's new labels.
+    labels: Vec<i64>,
+}
+
+impl AddIssueLabelsBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        issue_number: i64,
+        labels: Vec<i64>,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue_number,
+            labels,
+        }
+    }
+    /// Send the request to add the labels.
+    /// Returns all labels of the issue after the change.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Label>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.issue_number;
+            let req = client
+                .post(format!("repos/{owner}/{repo}/issues/{index}/labels"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl ReplaceIssueLabelsBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        issue_number: i64,
+        labels: Vec<i64>,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue_number,
+            labels,
+        }
+    }
+    /// Send the request to replace the labels.
+    /// Returns all labels of the issue after the change.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Label>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.issue_number;
+            let req = client
+                .put(format!("repos/{owner}/{repo}/issues/{index}/labels"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveIssueLabelBuilder {
+    owner: String,
+    repo: String,
+    issue_number: i64,
+    label: i64,
+}
+
+impl RemoveIssueLabelBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue_number: i64, label: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue_number,
+            label,
+        }
+    }
+    /// Send the request to remove the label from the issue.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.issue_number;
+            let label = self.label;
+            let req = client
+                .delete(format!(
+                    "repos/{owner}/{repo}/issues/{index}/labels/{label}"
+                ))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClearIssueLabelsBuilder {
+    owner: String,
+    repo: String,
+    issue_number: i64,
+}
+
+impl ClearIssueLabelsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, issue_number: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            issue_number,
+        }
+    }
+    /// Send the request to remove all labels from the issue.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.issue_number;
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/issues/{index}/labels"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
@@ -3,6 +3,10 @@ use serde::Serialize;
 
 use crate::error::Result;
 use crate::model::issues::{Issue, IssueType, State};
+use crate::model::Timestamp;
+use crate::pagination::Paginated;
+use crate::path::segment;
+use crate::Executor;
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -27,10 +31,12 @@ pub struct ListIssuesBuilder {
     /// Comma-separated list of milestone names or ids. It uses names and fall back to ids.
     /// Fetch only issues that have any of this milestones. Non existent milestones are discarded
     pub milestone: Option<String>,
-    /// Only show items updated after the given time. This is a timestamp in RFC 3339 format
-    pub since: Option<String>,
-    /// Only show items updated before the given time. This is a timestamp in RFC 3339 format
-    pub before: Option<String>,
+    /// Only show items updated after the given time.
+    /// Accepts an RFC 3339 string, or a `chrono::DateTime` with the `chrono` feature.
+    pub since: Option<Timestamp>,
+    /// Only show items updated before the given time.
+    /// Accepts an RFC 3339 string, or a `chrono::DateTime` with the `chrono` feature.
+    pub before: Option<Timestamp>,
     /// Only show items which were created by the given user
     pub created_by: Option<String>,
     /// Only show items for which the given user is assigned
@@ -63,14 +69,34 @@ impl ListIssuesBuilder {
         }
     }
     /// Send the request to get the issues.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Issue>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .get(format!("repos/{owner}/{repo}/issues"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Issue>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListIssuesBuilder {
+    type Item = Issue;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        format!("repos/{owner}/{repo}/issues")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
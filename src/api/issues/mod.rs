@@ -3,6 +3,7 @@ pub mod create;
 pub mod delete;
 pub mod edit;
 pub mod get;
+pub mod labels;
 pub mod list;
 
 pub struct Issues {
@@ -165,4 +166,121 @@ impl Issues {
             repo: self.repo.clone(),
         }
     }
+
+    /// Lists the labels of an issue or pull request.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_issue_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .issues("owner", "repo")
+    ///     .list_labels(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_labels(&self, issue_number: i64) -> labels::ListIssueLabelsBuilder {
+        labels::ListIssueLabelsBuilder::new(&self.owner, &self.repo, issue_number)
+    }
+
+    /// Adds labels to an issue or pull request.
+    /// Labels are identified by their ID, use [Labels::find](crate::api::labels::Labels::find) to
+    /// look up the ID of a label by its name. Returns all labels of the issue after the change.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_issue_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .issues("owner", "repo")
+    ///     .add_labels(1, vec![1, 2])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add_labels(&self, issue_number: i64, labels: Vec<i64>) -> labels::AddIssueLabelsBuilder {
+        labels::AddIssueLabelsBuilder::new(&self.owner, &self.repo, issue_number, labels)
+    }
+
+    /// Replaces all labels of an issue or pull request with the given ones.
+    /// Returns all labels of the issue after the change.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn replace_issue_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .issues("owner", "repo")
+    ///     .replace_labels(1, vec![3])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn replace_labels(
+        &self,
+        issue_number: i64,
+        labels: Vec<i64>,
+    ) -> labels::ReplaceIssueLabelsBuilder {
+        labels::ReplaceIssueLabelsBuilder::new(&self.owner, &self.repo, issue_number, labels)
+    }
+
+    /// Removes a label from an issue or pull request.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_issue_label() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .remove_label(1, 2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn remove_label(&self, issue_number: i64, label: i64) -> labels::RemoveIssueLabelBuilder {
+        labels::RemoveIssueLabelBuilder::new(&self.owner, &self.repo, issue_number, label)
+    }
+
+    /// Removes all labels from an issue or pull request.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn clear_issue_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .issues("owner", "repo")
+    ///     .clear_labels(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn clear_labels(&self, issue_number: i64) -> labels::ClearIssueLabelsBuilder {
+        labels::ClearIssueLabelsBuilder::new(&self.owner, &self.repo, issue_number)
+    }
 }
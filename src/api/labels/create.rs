@@ -0,0 +1,54 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::issues::Label, Executor};
+
+/// Options for creating a label.
+/// The name and color are required.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateLabelBuilder {
+    #[skip]
+    #[serde(skip)]
+    path: String,
+    /// The name of the label.
+    #[skip]
+    name: String,
+    /// The color of the label as a hex color code, like `#ee0701`.
+    #[skip]
+    color: String,
+    /// The description of the label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// Whether the label is exclusive.
+    /// Only one exclusive label of the same scope (the part of the name before the last `/`)
+    /// can be added to an issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclusive: Option<bool>,
+    /// Whether the label is archived.
+    /// Archived labels are hidden when adding labels to an issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_archived: Option<bool>,
+}
+
+impl CreateLabelBuilder {
+    pub(crate) fn new(path: String, name: impl ToString, color: impl ToString) -> Self {
+        Self {
+            path,
+            name: name.to_string(),
+            color: color.to_string(),
+            description: None,
+            exclusive: None,
+            is_archived: None,
+        }
+    }
+    /// Send the request to create the label.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Label>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.post(&self.path).json(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{error::Result, Executor};
+
+#[derive(Debug, Clone)]
+pub struct DeleteLabelBuilder {
+    path: String,
+    id: i64,
+}
+
+impl DeleteLabelBuilder {
+    pub(crate) fn new(path: String, id: i64) -> Self {
+        Self { path, id }
+    }
+    /// Send the request to delete the label.
+    /// The label is removed from all issues and pull requests it was added to.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client
+                .delete(format!("{}/{}", self.path, self.id))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
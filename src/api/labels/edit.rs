@@ -0,0 +1,58 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::issues::Label, Executor};
+
+/// Options for editing a label.
+/// Fields that are not set are left unchanged.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditLabelBuilder {
+    #[skip]
+    #[serde(skip)]
+    path: String,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// The name of the label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// The color of the label as a hex color code, like `#ee0701`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    /// The description of the label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// Whether the label is exclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclusive: Option<bool>,
+    /// Whether the label is archived.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_archived: Option<bool>,
+}
+
+impl EditLabelBuilder {
+    pub(crate) fn new(path: String, id: i64) -> Self {
+        Self {
+            path,
+            id,
+            name: None,
+            color: None,
+            description: None,
+            exclusive: None,
+            is_archived: None,
+        }
+    }
+    /// Send the request to edit the label.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Label>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client
+                .patch(format!("{}/{}", self.path, self.id))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
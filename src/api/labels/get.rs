@@ -0,0 +1,22 @@
+use crate::{error::Result, model::issues::Label, Executor};
+
+#[derive(Debug, Clone)]
+pub struct GetLabelBuilder {
+    path: String,
+    id: i64,
+}
+
+impl GetLabelBuilder {
+    pub(crate) fn new(path: String, id: i64) -> Self {
+        Self { path, id }
+    }
+    /// Send the request to get the label.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Label>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(format!("{}/{}", self.path, self.id)).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
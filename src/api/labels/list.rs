@@ -0,0 +1,95 @@
+use build_it::Builder;
+use futures_util::TryStreamExt;
+use serde::Serialize;
+
+use crate::{error::Result, model::issues::Label, pagination::Paginated, Executor};
+
+/// Options for listing labels.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListLabelsBuilder {
+    #[skip]
+    #[serde(skip)]
+    path: String,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListLabelsBuilder {
+    pub(crate) fn new(path: String) -> Self {
+        Self {
+            path,
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the labels.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Label>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListLabelsBuilder {
+    type Item = Label;
+
+    fn path(&self) -> String {
+        self.path.clone()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+/// Looks up a label by its name.
+/// Gitea has no endpoint for this, so the labels are listed page by page until the label is
+/// found.
+#[derive(Debug, Clone)]
+pub struct FindLabelBuilder {
+    path: String,
+    name: String,
+}
+
+impl FindLabelBuilder {
+    pub(crate) fn new(path: String, name: impl ToString) -> Self {
+        Self {
+            path,
+            name: name.to_string(),
+        }
+    }
+    /// Send the requests to find the label.
+    /// Returns `None` if there is no label with the given name.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Option<Label>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let mut labels = ListLabelsBuilder::new(self.path.clone()).stream(client);
+            while let Some(label) = labels.try_next().await? {
+                if label.name == self.name {
+                    return Ok(Some(label));
+                }
+            }
+            Ok(None)
+        })
+    }
+}
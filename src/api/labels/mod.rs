@@ -0,0 +1,212 @@
+pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod list;
+pub mod templates;
+
+/// The [Labels] struct provides methods for managing labels.
+/// It is used for both repository labels ([Repos::labels](crate::api::repos::Repos::labels))
+/// and organization labels ([Orgs::labels](crate::api::orgs::Orgs::labels)).
+/// Organization labels can be used in all of the organization's repositories.
+pub struct Labels {
+    /// The API path of the labels, like `repos/{owner}/{repo}/labels`.
+    pub(crate) path: String,
+}
+
+impl Labels {
+    /// Lists the labels.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .repos("owner", "repo")
+    ///     .labels()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> list::ListLabelsBuilder {
+        list::ListLabelsBuilder::new(self.path.clone())
+    }
+
+    /// Finds a label by its name.
+    /// This returns `None` if there is no label with the given name. Use it to look up the ID of a
+    /// label, for example to add it to an issue.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn find_label() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let label = client
+    ///     .repos("owner", "repo")
+    ///     .labels()
+    ///     .find("bug")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn find(&self, name: impl ToString) -> list::FindLabelBuilder {
+        list::FindLabelBuilder::new(self.path.clone(), name)
+    }
+
+    /// Gets a label by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_label() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let label = client
+    ///     .repos("owner", "repo")
+    ///     .labels()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, id: i64) -> get::GetLabelBuilder {
+        get::GetLabelBuilder::new(self.path.clone(), id)
+    }
+
+    /// Creates a label with the given name and color.
+    /// The color is a hex color code, like `#ee0701`.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_label() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let label = client
+    ///     .repos("owner", "repo")
+    ///     .labels()
+    ///     .create("bug", "#ee0701")
+    ///     .description("Something isn't working")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create(&self, name: impl ToString, color: impl ToString) -> create::CreateLabelBuilder {
+        create::CreateLabelBuilder::new(self.path.clone(), name, color)
+    }
+
+    /// Edits a label.
+    /// Fields that are not set are left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_label() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let label = client
+    ///     .repos("owner", "repo")
+    ///     .labels()
+    ///     .edit(1)
+    ///     .name("type/bug")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self, id: i64) -> edit::EditLabelBuilder {
+        edit::EditLabelBuilder::new(self.path.clone(), id)
+    }
+
+    /// Deletes a label.
+    /// The label is removed from all issues and pull requests it was added to.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_label() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .labels()
+    ///     .delete(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, id: i64) -> delete::DeleteLabelBuilder {
+        delete::DeleteLabelBuilder::new(self.path.clone(), id)
+    }
+}
+
+/// The [LabelTemplates] struct provides methods for reading the label templates available on
+/// the instance.
+pub struct LabelTemplates;
+
+impl LabelTemplates {
+    /// Lists the names of the label templates available on the instance.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_label_templates() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let names = client
+    ///     .label_templates()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> templates::ListLabelTemplatesBuilder {
+        templates::ListLabelTemplatesBuilder::new()
+    }
+
+    /// Gets the labels of a label template.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_label_template() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .label_templates()
+    ///     .get("Default")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, name: impl ToString) -> templates::GetLabelTemplateBuilder {
+        templates::GetLabelTemplateBuilder::new(name)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{error::Result, model::issues::LabelTemplate, path::segment, Executor};
+
+#[derive(Debug, Clone, Default)]
+pub struct ListLabelTemplatesBuilder {}
+
+impl ListLabelTemplatesBuilder {
+    pub fn new() -> Self {
+        Self {}
+    }
+    /// Send the request to list the names of the available label templates.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<String>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get("label/templates").build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetLabelTemplateBuilder {
+    name: String,
+}
+
+impl GetLabelTemplateBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+    /// Send the request to get the labels of the template.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<LabelTemplate>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let name = segment(&self.name);
+            let req = client.get(format!("label/templates/{name}")).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{model::repos::Repository, Result};
+use crate::{model::repos::Repository, Executor, Result};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Builder)]
 #[build_it(into)]
@@ -56,9 +56,12 @@ impl MigrateRepoBuilder {
     }
 
     /// Send the request to migrate a repository.
-    pub async fn send(&self, client: &crate::Client) -> Result<Repository> {
-        let req = client.post("repos/migrate").json(&self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Repository>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.post("repos/migrate").json(&self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
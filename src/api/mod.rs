@@ -1,8 +1,12 @@
+pub mod admin;
+pub mod hooks;
 pub mod issues;
+pub mod labels;
 pub mod migrate;
 pub mod orgs;
 pub mod pulls;
 pub mod repos;
 pub mod search;
+pub mod teams;
 pub mod user;
 pub mod users;
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     error::Result,
     model::orgs::{Organization, Visibility},
-    Client,
+    Executor,
 };
 
 #[derive(Debug, Serialize, Deserialize, Builder)]
@@ -35,9 +35,12 @@ impl CreateOrgBuilder {
         }
     }
     /// Send the request to create an [Organization].
-    pub async fn send(&self, client: &Client) -> Result<Organization> {
-        let req = client.post("orgs").json(&self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Organization>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.post("orgs").json(&self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
@@ -4,7 +4,8 @@ use serde::Serialize;
 use crate::{
     error::Result,
     model::repos::{ObjectFormatName, Repository, TrustModel},
-    Client,
+    path::segment,
+    Executor,
 };
 
 /// Represents the options for creating a new repository.
@@ -67,10 +68,16 @@ impl CreateRepoBuilder {
     }
     /// Send the request to create the repository.
     /// This will return the created [Repository].
-    pub async fn send(&self, client: &Client) -> Result<Repository> {
-        let org = &self.org;
-        let req = client.post(format!("orgs/{org}/repos")).json(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Repository>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let org = segment(&self.org);
+            let req = client
+                .post(format!("orgs/{org}/repos"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
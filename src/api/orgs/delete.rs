@@ -1,3 +1,6 @@
+use crate::path::segment;
+use crate::Executor;
+
 pub struct DeleteOrgBuilder {
     name: String,
 }
@@ -9,9 +12,17 @@ impl DeleteOrgBuilder {
         }
     }
     /// Send the request to delete an [Organization](crate::model::orgs::Organization).
-    pub async fn send(&self, client: &crate::Client) -> crate::error::Result<()> {
-        let req = client.delete(format!("orgs/{}", self.name)).build()?;
-        client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, crate::error::Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client
+                .delete(format!("orgs/{}", segment(&self.name)))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
     }
 }
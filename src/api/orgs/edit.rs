@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 use crate::{
     error::Result,
     model::orgs::{Organization, Visibility},
-    Client,
+    path::segment,
+    Executor,
 };
 
 #[derive(Debug, Serialize, Deserialize, Builder)]
@@ -35,12 +36,15 @@ impl EditOrgBuilder {
             website: None,
         }
     }
-    pub async fn send(&self, client: &Client) -> Result<Organization> {
-        let req = client
-            .patch(format!("orgs/{}", self.name))
-            .json(&self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Organization>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client
+                .patch(format!("orgs/{}", segment(&self.name)))
+                .json(&self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
@@ -1,4 +1,4 @@
-use crate::{error::Result, model::orgs::Organization, Client};
+use crate::{error::Result, model::orgs::Organization, path::segment, Executor};
 
 pub struct GetOrgBuilder {
     name: String,
@@ -11,9 +11,14 @@ impl GetOrgBuilder {
         }
     }
     /// Send the request to get an [Organization].
-    pub async fn send(&self, client: &Client) -> Result<Organization> {
-        let req = client.get(format!("orgs/{}", self.name)).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Organization>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client
+                .get(format!("orgs/{}", segment(&self.name)))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
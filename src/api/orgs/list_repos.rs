@@ -1,7 +1,9 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Repository, Client};
+use crate::{
+    error::Result, model::repos::Repository, pagination::Paginated, path::segment, Executor,
+};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 #[build_it(into)]
@@ -24,12 +26,35 @@ impl ListReposBuilder {
         }
     }
     /// Sends the request to list an organization's repositories.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Repository>> {
-        let req = client
-            .get(format!("/orgs/{}/repos", self.org))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<Repository>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListReposBuilder {
+    type Item = Repository;
+
+    fn path(&self) -> String {
+        format!("orgs/{}/repos", segment(&self.org))
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
@@ -6,6 +6,9 @@ pub mod get;
 pub mod list_repos;
 pub mod members;
 pub mod public_members;
+pub mod teams;
+
+use crate::path::segment;
 
 pub struct Orgs {
     pub name: String,
@@ -325,4 +328,127 @@ impl Orgs {
     ) -> public_members::PublicizeMembershipBuilder {
         public_members::PublicizeMembershipBuilder::new(self.name.clone(), username)
     }
+
+    /// Methods for managing the organization's labels.
+    /// Organization labels can be used in all of the organization's repositories.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_org_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .orgs("org-name")
+    ///     .labels()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn labels(&self) -> crate::api::labels::Labels {
+        crate::api::labels::Labels {
+            path: format!("orgs/{}/labels", segment(&self.name)),
+        }
+    }
+
+    /// Methods for managing the organization's webhooks.
+    /// Organization webhooks are delivered for events in all of the organization's repositories.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_org_hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .orgs("org-name")
+    ///     .hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn hooks(&self) -> crate::api::hooks::Hooks {
+        crate::api::hooks::Hooks {
+            path: format!("orgs/{}/hooks", segment(&self.name)),
+        }
+    }
+
+    /// Lists the teams of an [Organization](crate::model::orgs::Organization).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_teams() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let teams = client
+    ///     .orgs("org-name")
+    ///     .list_teams()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_teams(&self) -> teams::ListTeamsBuilder {
+        teams::ListTeamsBuilder::new(self.name.clone())
+    }
+
+    /// Searches the teams of an [Organization](crate::model::orgs::Organization) by name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn search_teams() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let teams = client
+    ///     .orgs("org-name")
+    ///     .search_teams("core")
+    ///     .include_desc(true)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn search_teams(&self, q: impl ToString) -> teams::SearchTeamsBuilder {
+        teams::SearchTeamsBuilder::new(self.name.clone(), q)
+    }
+
+    /// Creates a team in an [Organization](crate::model::orgs::Organization).
+    /// Without [units](teams::CreateTeamBuilder::units), the team has no access to any repository
+    /// units.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::team::{Permission, RepoUnit}};
+    /// # async fn create_team() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let team = client
+    ///     .orgs("org-name")
+    ///     .create_team("core")
+    ///     .permission(Permission::Write)
+    ///     .units(vec![RepoUnit::Code, RepoUnit::PullRequests])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create_team(&self, name: impl ToString) -> teams::CreateTeamBuilder {
+        teams::CreateTeamBuilder::new(self.name.clone(), name)
+    }
 }
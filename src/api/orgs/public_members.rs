@@ -1,8 +1,13 @@
 use build_it::Builder;
-use reqwest::StatusCode;
 use serde::Serialize;
 
-use crate::{error::Result, model::user::User, Client};
+use crate::{
+    error::{Result, TeatimeErrorKind},
+    model::user::User,
+    pagination::Paginated,
+    path::segment,
+    Executor,
+};
 
 #[derive(Debug, Clone, Builder, Serialize)]
 #[build_it(into)]
@@ -44,13 +49,13 @@ impl ListPublicMembersBuilder {
     }
     /// Sends the request to list an organization's public members.
     /// This will return a list of [User] objects.
-    pub async fn send(&self, client: &Client) -> Result<Vec<User>> {
-        let req = client
-            .get(format!("/orgs/{}/public_members", self.org))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<User>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -62,21 +67,25 @@ impl IsPublicMemberBuilder {
         }
     }
     /// Sends the request to check if a user is a public member of an organization.
-    pub async fn send(&self, client: &Client) -> Result<bool> {
-        let Self { org, username } = self;
-        let req = client
-            .get(format!("/orgs/{org}/public_members/{username}"))
-            .build()?;
-        match client.make_request(req).await {
-            Ok(_) => Ok(true),
-            Err(e) => {
-                if e.status_code == StatusCode::NOT_FOUND {
-                    Ok(false)
-                } else {
-                    Err(e)
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<bool>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let org = segment(&self.org);
+            let username = segment(&self.username);
+            let req = client
+                .get(format!("/orgs/{org}/public_members/{username}"))
+                .build()?;
+            match client.make_request(req).await {
+                Ok(_) => Ok(true),
+                Err(e) => {
+                    if e.kind == TeatimeErrorKind::NotFound {
+                        Ok(false)
+                    } else {
+                        Err(e)
+                    }
                 }
             }
-        }
+        })
     }
 }
 
@@ -88,13 +97,17 @@ impl ConcealMembershipBuilder {
         }
     }
     /// Sends the request to conceal a user's membership in an organization.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let Self { org, username } = self;
-        let req = client
-            .delete(format!("/orgs/{org}/public_members/{username}"))
-            .build()?;
-        let _ = client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let org = segment(&self.org);
+            let username = segment(&self.username);
+            let req = client
+                .delete(format!("/orgs/{org}/public_members/{username}"))
+                .build()?;
+            let _ = client.make_request(req).await?;
+            Ok(())
+        })
     }
 }
 
@@ -106,12 +119,36 @@ impl PublicizeMembershipBuilder {
         }
     }
     /// Sends the request to publicize a user's membership in an organization.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let Self { org, username } = self;
-        let req = client
-            .put(format!("/orgs/{org}/public_members/{username}"))
-            .build()?;
-        let _ = client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let org = segment(&self.org);
+            let username = segment(&self.username);
+            let req = client
+                .put(format!("/orgs/{org}/public_members/{username}"))
+                .build()?;
+            let _ = client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+impl Paginated for ListPublicMembersBuilder {
+    type Item = User;
+
+    fn path(&self) -> String {
+        format!("orgs/{}/public_members", segment(&self.org))
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use build_it::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    error::Result,
+    model::team::{Permission, RepoUnit, Team},
+    pagination::{decode, Paginated},
+    path::segment,
+    Executor,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTeamsBuilder {
+    #[skip]
+    #[serde(skip)]
+    org: String,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListTeamsBuilder {
+    pub fn new(org: impl ToString) -> Self {
+        Self {
+            org: org.to_string(),
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the organization's teams.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Team>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListTeamsBuilder {
+    type Item = Team;
+
+    fn path(&self) -> String {
+        format!("orgs/{}/teams", segment(&self.org))
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+/// Options for searching an organization's teams.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct SearchTeamsBuilder {
+    #[skip]
+    #[serde(skip)]
+    org: String,
+    /// The text to search for in the teams' names.
+    #[skip]
+    q: String,
+    /// Whether to search the teams' descriptions too.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_desc: Option<bool>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct SearchTeamsResponse {
+    data: Vec<Team>,
+}
+
+impl SearchTeamsBuilder {
+    pub fn new(org: impl ToString, q: impl ToString) -> Self {
+        Self {
+            org: org.to_string(),
+            q: q.to_string(),
+            include_desc: None,
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to search the teams.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Team>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            let res: SearchTeamsResponse = client.parse_response(res).await?;
+            Ok(res.data)
+        })
+    }
+}
+
+impl Paginated for SearchTeamsBuilder {
+    type Item = Team;
+
+    fn path(&self) -> String {
+        format!("orgs/{}/teams/search", segment(&self.org))
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+
+    fn items(body: Value) -> Result<Vec<Self::Item>> {
+        Ok(decode::<SearchTeamsResponse>(body)?.data)
+    }
+}
+
+/// Options for creating a team.
+/// The only required field is the name.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateTeamBuilder {
+    #[skip]
+    #[serde(skip)]
+    org: String,
+    /// The name of the team.
+    #[skip]
+    name: String,
+    /// The description of the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// The team's permission for all units. Use [units_map](Self::units_map) for per-unit
+    /// permissions instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permission: Option<Permission>,
+    /// Whether the team has access to all repositories of the organization, including ones
+    /// created later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    includes_all_repositories: Option<bool>,
+    /// Whether members of the team may create repositories in the organization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_create_org_repo: Option<bool>,
+    /// The repository units the team has access to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units: Option<Vec<RepoUnit>>,
+    /// The team's permission for each repository unit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units_map: Option<HashMap<RepoUnit, Permission>>,
+}
+
+impl CreateTeamBuilder {
+    pub fn new(org: impl ToString, name: impl ToString) -> Self {
+        Self {
+            org: org.to_string(),
+            name: name.to_string(),
+            description: None,
+            permission: None,
+            includes_all_repositories: None,
+            can_create_org_repo: None,
+            units: None,
+            units_map: None,
+        }
+    }
+    /// Send the request to create the team.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Team>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let org = segment(&self.org);
+            let req = client
+                .post(format!("orgs/{org}/teams"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
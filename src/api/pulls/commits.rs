@@ -0,0 +1,82 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::repos::Commit, pagination::Paginated, path::segment, Executor};
+
+/// Options for listing the commits of a pull request.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListPullRequestCommitsBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    index: i64,
+    /// Whether to include the `verification` field in the response.
+    /// Disable to speed-up the response.
+    /// Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification: Option<bool>,
+    /// Whether to include the `files` field in the response.
+    /// Disable to speed-up the response.
+    /// Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<bool>,
+    /// Page number of results to return (1-based)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListPullRequestCommitsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            verification: None,
+            files: None,
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the pull request's commits.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Commit>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListPullRequestCommitsBuilder {
+    type Item = Commit;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        let index = self.index;
+        format!("repos/{owner}/{repo}/pulls/{index}/commits")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
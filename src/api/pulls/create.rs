@@ -1,7 +1,12 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::pulls::PullRequest, Client};
+use crate::{
+    error::Result,
+    model::{pulls::PullRequest, Timestamp},
+    path::segment,
+    Executor,
+};
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -22,7 +27,7 @@ pub struct CreatePullRequestBuilder {
 
     assignees: Option<Vec<String>>,
     body: Option<String>,
-    due_date: Option<String>,
+    due_date: Option<Timestamp>,
     labels: Option<Vec<i64>>,
     milestone: Option<i64>,
 }
@@ -50,14 +55,17 @@ impl CreatePullRequestBuilder {
     }
 
     /// Sends the request to create a pull request
-    pub async fn send(&self, client: &Client) -> Result<PullRequest> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .post(format!("repos/{owner}/{repo}/pulls",))
-            .json(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<PullRequest>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/pulls",))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
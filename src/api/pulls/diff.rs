@@ -0,0 +1,66 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::{diff::Diff, pulls::DiffType},
+    path::segment,
+    Executor,
+};
+
+/// Options for getting a pull request's changes as a diff or patch.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct GetPullRequestDiffBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    index: i64,
+    #[skip]
+    #[serde(skip)]
+    diff_type: DiffType,
+    /// Whether to include the contents of binary files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    binary: Option<bool>,
+}
+
+impl GetPullRequestDiffBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64, diff_type: DiffType) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            diff_type,
+            binary: None,
+        }
+    }
+    /// Send the request to get the raw diff or patch.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<String>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.index;
+            let diff_type = self.diff_type.as_str();
+            let req = client
+                .get(format!("repos/{owner}/{repo}/pulls/{index}.{diff_type}"))
+                .query(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            Ok(res.text().await?)
+        })
+    }
+    /// Send the request and parse the diff or patch into a [Diff].
+    pub fn send_parsed<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Diff>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let text = Self::send(self, client).await?;
+            Ok(Diff::parse(&text))
+        })
+    }
+}
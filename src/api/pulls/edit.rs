@@ -3,8 +3,9 @@ use serde::Serialize;
 
 use crate::{
     error::Result,
-    model::{issues::StateType, pulls::PullRequest},
-    Client,
+    model::{issues::StateType, pulls::PullRequest, Timestamp},
+    path::segment,
+    Executor,
 };
 
 #[derive(Debug, Clone, Serialize, Builder)]
@@ -24,7 +25,7 @@ pub struct EditPullRequestBuilder {
     assignees: Option<Vec<String>>,
     base: Option<String>,
     body: Option<String>,
-    due_date: Option<String>,
+    due_date: Option<Timestamp>,
     labels: Option<Vec<i64>>,
     milestone: Option<i64>,
     state: Option<StateType>,
@@ -52,15 +53,18 @@ impl EditPullRequestBuilder {
     }
 
     /// Sends the request to edit a pull request
-    pub async fn send(&self, client: &Client) -> Result<PullRequest> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let id = self.id;
-        let req = client
-            .patch(format!("repos/{owner}/{repo}/pulls/{id}"))
-            .json(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<PullRequest>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .patch(format!("repos/{owner}/{repo}/pulls/{id}"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
@@ -0,0 +1,97 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result, model::pulls::ChangedFile, pagination::Paginated, path::segment, Executor,
+};
+
+/// How whitespace changes are treated when comparing files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WhitespaceBehavior {
+    /// Show all whitespace changes.
+    ShowAll,
+    /// Ignore all whitespace.
+    IgnoreAll,
+    /// Ignore changes in the amount of whitespace.
+    IgnoreChange,
+    /// Ignore whitespace at the end of lines.
+    IgnoreEol,
+}
+
+/// Options for listing the files changed by a pull request.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListPullRequestFilesBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    index: i64,
+    /// Skip all files up to (and excluding) the given path.
+    #[serde(rename = "skip-to", skip_serializing_if = "Option::is_none")]
+    skip_to: Option<String>,
+    /// How whitespace changes are counted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    whitespace: Option<WhitespaceBehavior>,
+    /// Page number of results to return (1-based)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListPullRequestFilesBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            skip_to: None,
+            whitespace: None,
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the changed files.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<ChangedFile>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListPullRequestFilesBuilder {
+    type Item = ChangedFile;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        let index = self.index;
+        format!("repos/{owner}/{repo}/pulls/{index}/files")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{error::Result, model::pulls::PullRequest, Client};
+use crate::{
+    error::Result,
+    model::pulls::PullRequest,
+    path::{segment, segments},
+    Executor,
+};
 
 #[derive(Debug, Clone)]
 pub struct GetPullRequestByIdBuilder {
@@ -16,13 +21,18 @@ impl GetPullRequestByIdBuilder {
         }
     }
     /// Sends the request to get a pull request by its ID.
-    pub async fn send(&self, client: &Client) -> Result<PullRequest> {
-        let Self { owner, repo, id } = self;
-        let req = client
-            .get(format!("/repos/{owner}/{repo}/pulls/{id}"))
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<PullRequest>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .get(format!("/repos/{owner}/{repo}/pulls/{id}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -49,17 +59,18 @@ impl GetPullRequestByBranchesBuilder {
         }
     }
     /// Sends the request to get a pull request by its head and base branches.
-    pub async fn send(&self, client: &Client) -> Result<PullRequest> {
-        let Self {
-            owner,
-            repo,
-            head,
-            base,
-        } = self;
-        let req = client
-            .get(format!("/repos/{owner}/{repo}/pulls/{base}/{head}"))
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<PullRequest>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let base = segment(&self.base);
+            let head = segments(&self.head);
+            let req = client
+                .get(format!("/repos/{owner}/{repo}/pulls/{base}/{head}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
@@ -7,7 +7,9 @@ use crate::{
         issues::State,
         pulls::{PullRequest, Sort},
     },
-    Client,
+    pagination::Paginated,
+    path::segment,
+    Executor,
 };
 
 #[derive(Debug, Clone, Serialize, Builder)]
@@ -45,14 +47,37 @@ impl ListPullRequestsBuilder {
         }
     }
     /// Sends the request to list pull requests.
-    pub async fn send(&self, client: &Client) -> Result<Vec<PullRequest>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .get(format!("/repos/{owner}/{repo}/pulls"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<PullRequest>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListPullRequestsBuilder {
+    type Item = PullRequest;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        format!("repos/{owner}/{repo}/pulls")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
@@ -0,0 +1,160 @@
+use build_it::Builder;
+use reqwest::StatusCode;
+use serde::Serialize;
+
+use crate::{
+    error::{Result, TeatimeErrorKind},
+    model::pulls::{MergeResult, MergeStyle},
+    path::segment,
+    Executor,
+};
+
+/// Options for merging a pull request.
+/// Only the merge style is required.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct MergePullRequestBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    index: i64,
+    #[skip]
+    #[serde(rename = "Do")]
+    style: MergeStyle,
+    /// The title of the merge commit.
+    /// Defaults to a title generated by Gitea.
+    #[serde(rename = "MergeTitleField", skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    /// The message of the merge commit.
+    #[serde(rename = "MergeMessageField", skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    /// The SHA of the commit the pull request was merged in.
+    /// Only used with [MergeStyle::ManuallyMerged].
+    #[serde(rename = "MergeCommitID", skip_serializing_if = "Option::is_none")]
+    merge_commit_id: Option<String>,
+    /// The SHA the head branch is expected to point to.
+    /// The merge fails if the head branch was changed in the meantime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    head_commit_id: Option<String>,
+    /// Whether to delete the head branch after the merge.
+    /// Defaults to the repository's setting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_branch_after_merge: Option<bool>,
+    /// Whether to merge even if the branch protection rules are not satisfied.
+    /// Only allowed for repository admins if the branch protection allows it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    force_merge: Option<bool>,
+    /// Whether to schedule the merge for when all checks succeed instead of merging right away.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_when_checks_succeed: Option<bool>,
+}
+
+impl MergePullRequestBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64, style: MergeStyle) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            style,
+            title: None,
+            message: None,
+            merge_commit_id: None,
+            head_commit_id: None,
+            delete_branch_after_merge: None,
+            force_merge: None,
+            merge_when_checks_succeed: None,
+        }
+    }
+    /// Send the request to merge the pull request.
+    /// If the pull request can't be merged (for example because of conflicts or missing
+    /// approvals), an error of kind [HttpError](TeatimeErrorKind::HttpError) (405) or
+    /// [Conflict](TeatimeErrorKind::Conflict) is returned.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<MergeResult>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.index;
+            let req = client
+                .post(format!("repos/{owner}/{repo}/pulls/{index}/merge"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            if res.status() == StatusCode::CREATED {
+                Ok(MergeResult::Scheduled)
+            } else {
+                Ok(MergeResult::Merged)
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CancelScheduledMergeBuilder {
+    owner: String,
+    repo: String,
+    index: i64,
+}
+
+impl CancelScheduledMergeBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+        }
+    }
+    /// Send the request to cancel the scheduled merge.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.index;
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/pulls/{index}/merge"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IsMergedBuilder {
+    owner: String,
+    repo: String,
+    index: i64,
+}
+
+impl IsMergedBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+        }
+    }
+    /// Send the request to check whether the pull request is merged.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<bool>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.index;
+            let req = client
+                .get(format!("repos/{owner}/{repo}/pulls/{index}/merge"))
+                .build()?;
+            match client.make_request(req).await {
+                Ok(_) => Ok(true),
+                Err(e) if e.kind == TeatimeErrorKind::NotFound => Ok(false),
+                Err(e) => Err(e),
+            }
+        })
+    }
+}
@@ -1,9 +1,17 @@
+pub mod commits;
 pub mod create;
+pub mod diff;
 pub mod edit;
+pub mod files;
 pub mod get;
 pub mod list;
+pub mod merge;
 pub mod pinned;
+pub mod reviewers;
 pub mod reviews;
+pub mod update;
+
+use crate::model::pulls::{DiffType, MergeStyle};
 
 pub struct Pulls {
     pub(crate) owner: String,
@@ -169,4 +177,241 @@ impl Pulls {
     pub fn reviews(&self) -> reviews::Reviews {
         reviews::Reviews::new(&self.owner, &self.repo)
     }
+
+    /// Merges a pull request with the given [MergeStyle].
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::pulls::MergeStyle};
+    /// # async fn merge_pr() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let result = client
+    ///     .pulls("owner", "repo")
+    ///     .merge(1, MergeStyle::Squash)
+    ///     .title("Add feature (#1)")
+    ///     .delete_branch_after_merge(true)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will squash-merge the pull request #1 and delete its head branch.
+    ///
+    /// Set [merge_when_checks_succeed](merge::MergePullRequestBuilder::merge_when_checks_succeed)
+    /// to schedule the merge for when all checks succeed instead.
+    pub fn merge(&self, index: i64, style: MergeStyle) -> merge::MergePullRequestBuilder {
+        merge::MergePullRequestBuilder::new(&self.owner, &self.repo, index, style)
+    }
+
+    /// Cancels the scheduled merge of a pull request.
+    /// See [merge_when_checks_succeed](merge::MergePullRequestBuilder::merge_when_checks_succeed).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn cancel_scheduled_merge() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .pulls("owner", "repo")
+    ///     .cancel_scheduled_merge(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn cancel_scheduled_merge(&self, index: i64) -> merge::CancelScheduledMergeBuilder {
+        merge::CancelScheduledMergeBuilder::new(&self.owner, &self.repo, index)
+    }
+
+    /// Checks whether a pull request is merged.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn is_merged() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let merged = client
+    ///     .pulls("owner", "repo")
+    ///     .is_merged(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn is_merged(&self, index: i64) -> merge::IsMergedBuilder {
+        merge::IsMergedBuilder::new(&self.owner, &self.repo, index)
+    }
+
+    /// Updates a pull request's head branch with the changes of its base branch.
+    /// By default, the base branch is merged into the head branch. Set the
+    /// [style](update::UpdatePullRequestBuilder::style) to rebase the head branch instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::pulls::UpdateStyle};
+    /// # async fn update_pr() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .pulls("owner", "repo")
+    ///     .update(1)
+    ///     .style(UpdateStyle::Rebase)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn update(&self, index: i64) -> update::UpdatePullRequestBuilder {
+        update::UpdatePullRequestBuilder::new(&self.owner, &self.repo, index)
+    }
+
+    /// Gets the changes of a pull request as a unified diff.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn pr_diff() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let diff = client
+    ///     .pulls("owner", "repo")
+    ///     .diff(1)
+    ///     .send_parsed(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// Use [send](diff::GetPullRequestDiffBuilder::send) to get the raw text instead of a
+    /// [Diff](crate::model::diff::Diff).
+    pub fn diff(&self, index: i64) -> diff::GetPullRequestDiffBuilder {
+        diff::GetPullRequestDiffBuilder::new(&self.owner, &self.repo, index, DiffType::Diff)
+    }
+
+    /// Gets the commits of a pull request as patches, like `git format-patch`.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn pr_patch() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let patch = client
+    ///     .pulls("owner", "repo")
+    ///     .patch(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn patch(&self, index: i64) -> diff::GetPullRequestDiffBuilder {
+        diff::GetPullRequestDiffBuilder::new(&self.owner, &self.repo, index, DiffType::Patch)
+    }
+
+    /// Lists the [Commits](crate::model::repos::Commit) of a pull request.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn pr_commits() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let commits = client
+    ///     .pulls("owner", "repo")
+    ///     .commits(1)
+    ///     .files(false)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn commits(&self, index: i64) -> commits::ListPullRequestCommitsBuilder {
+        commits::ListPullRequestCommitsBuilder::new(&self.owner, &self.repo, index)
+    }
+
+    /// Lists the [files changed](crate::model::pulls::ChangedFile) by a pull request, with their
+    /// status and the number of added and deleted lines.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn pr_files() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let files = client
+    ///     .pulls("owner", "repo")
+    ///     .files(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn files(&self, index: i64) -> files::ListPullRequestFilesBuilder {
+        files::ListPullRequestFilesBuilder::new(&self.owner, &self.repo, index)
+    }
+
+    /// Request reviews of a pull request from users or teams.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn request_reviews() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let requests = client
+    ///     .pulls("owner", "repo")
+    ///     .request_reviews(1)
+    ///     .reviewer("alice")
+    ///     .team("maintainers")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn request_reviews(&self, index: i64) -> reviewers::RequestReviewsBuilder {
+        reviewers::RequestReviewsBuilder::new(&self.owner, &self.repo, index)
+    }
+
+    /// Cancel review requests of a pull request.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn cancel_review_requests() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .pulls("owner", "repo")
+    ///     .cancel_review_requests(1)
+    ///     .reviewer("alice")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn cancel_review_requests(&self, index: i64) -> reviewers::CancelReviewRequestsBuilder {
+        reviewers::CancelReviewRequestsBuilder::new(&self.owner, &self.repo, index)
+    }
 }
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::{error::Result, model::pulls::PullRequest, Client};
+use crate::{error::Result, model::pulls::PullRequest, path::segment, Executor};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct PinnedPullRequestsBuilder {
@@ -18,13 +18,19 @@ impl PinnedPullRequestsBuilder {
         }
     }
     /// Sends the request to list pinned pull requests.
-    pub async fn send(&self, client: &Client) -> Result<Vec<PullRequest>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .get(format!("/repos/{owner}/{repo}/pulls/pinned"))
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<PullRequest>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("/repos/{owner}/{repo}/pulls/pinned"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
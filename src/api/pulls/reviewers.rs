@@ -0,0 +1,116 @@
+use serde::Serialize;
+
+use crate::{error::Result, model::reviews::PullReview, path::segment, Executor};
+
+/// Requests reviews from users and teams.
+#[derive(Debug, Clone, Serialize)]
+pub struct RequestReviewsBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    index: i64,
+    /// The usernames of the requested reviewers.
+    reviewers: Vec<String>,
+    /// The names of the requested teams.
+    team_reviewers: Vec<String>,
+}
+
+/// Cancels review requests of users and teams.
+#[derive(Debug, Clone, Serialize)]
+pub struct CancelReviewRequestsBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    index: i64,
+    /// The usernames of the reviewers whose requests are cancelled.
+    reviewers: Vec<String>,
+    /// The names of the teams whose requests are cancelled.
+    team_reviewers: Vec<String>,
+}
+
+impl RequestReviewsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            reviewers: Vec::new(),
+            team_reviewers: Vec::new(),
+        }
+    }
+    /// Adds a user by their username.
+    pub fn reviewer(mut self, username: impl ToString) -> Self {
+        self.reviewers.push(username.to_string());
+        self
+    }
+    /// Adds a team of the repository's organization by its name.
+    pub fn team(mut self, name: impl ToString) -> Self {
+        self.team_reviewers.push(name.to_string());
+        self
+    }
+    /// Send the request.
+    /// This will return the created [PullReview]s, of state
+    /// [RequestReview](crate::model::reviews::ReviewStateType::RequestReview).
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<PullReview>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.index;
+            let req = client
+                .post(format!(
+                    "repos/{owner}/{repo}/pulls/{index}/requested_reviewers"
+                ))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl CancelReviewRequestsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            reviewers: Vec::new(),
+            team_reviewers: Vec::new(),
+        }
+    }
+    /// Adds a user by their username.
+    pub fn reviewer(mut self, username: impl ToString) -> Self {
+        self.reviewers.push(username.to_string());
+        self
+    }
+    /// Adds a team of the repository's organization by its name.
+    pub fn team(mut self, name: impl ToString) -> Self {
+        self.team_reviewers.push(name.to_string());
+        self
+    }
+    /// Send the request.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.index;
+            let req = client
+                .delete(format!(
+                    "repos/{owner}/{repo}/pulls/{index}/requested_reviewers"
+                ))
+                .json(self)
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
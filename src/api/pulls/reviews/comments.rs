@@ -0,0 +1,85 @@
+use crate::{error::Result, model::reviews::ReviewComment, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct ListReviewCommentsBuilder {
+    owner: String,
+    repo: String,
+    index: i64,
+    id: i64,
+}
+
+impl ListReviewCommentsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+        }
+    }
+    /// Send the request to list the review's comments.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<ReviewComment>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (index, id) = (self.index, self.id);
+            let req = client
+                .get(format!(
+                    "repos/{owner}/{repo}/pulls/{index}/reviews/{id}/comments"
+                ))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetReviewCommentBuilder {
+    owner: String,
+    repo: String,
+    index: i64,
+    id: i64,
+    comment: i64,
+}
+
+impl GetReviewCommentBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        index: i64,
+        id: i64,
+        comment: i64,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+            comment,
+        }
+    }
+    /// Send the request to get the comment.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<ReviewComment>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (index, id, comment) = (self.index, self.id, self.comment);
+            let req = client
+                .get(format!(
+                    "repos/{owner}/{repo}/pulls/{index}/reviews/{id}/comments/{comment}"
+                ))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
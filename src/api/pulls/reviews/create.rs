@@ -0,0 +1,72 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::reviews::{CreateReviewComment, PullReview, ReviewStateType},
+    path::segment,
+    Executor,
+};
+
+/// Options for creating a review of a pull request.
+/// All fields are optional. Without an [event](Self::event), the review is created as pending
+/// and can be submitted later.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateReviewBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    index: i64,
+    /// The review's summary.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    /// The SHA of the commit that was reviewed. Defaults to the head of the pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+    /// The verdict of the review, like [ReviewStateType::Approved].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event: Option<ReviewStateType>,
+    /// Line-level comments to post with the review.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comments: Option<Vec<CreateReviewComment>>,
+}
+
+impl CreateReviewBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            body: None,
+            commit_id: None,
+            event: None,
+            comments: None,
+        }
+    }
+    /// Adds a line-level comment to the review.
+    pub fn comment(mut self, comment: CreateReviewComment) -> Self {
+        self.comments.get_or_insert_with(Vec::new).push(comment);
+        self
+    }
+    /// Send the request to create the review.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<PullReview>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.index;
+            let req = client
+                .post(format!("repos/{owner}/{repo}/pulls/{index}/reviews"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{error::Result, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct DeleteReviewBuilder {
+    owner: String,
+    repo: String,
+    index: i64,
+    id: i64,
+}
+
+impl DeleteReviewBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+        }
+    }
+    /// Send the request to delete the review.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (index, id) = (self.index, self.id);
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/pulls/{index}/reviews/{id}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
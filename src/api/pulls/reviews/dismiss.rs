@@ -0,0 +1,94 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::reviews::PullReview, path::segment, Executor};
+
+/// Options for dismissing a review.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct DismissReviewBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    index: i64,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// The reason for dismissing the review.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    /// Whether to also dismiss the reviewer's earlier reviews.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priors: Option<bool>,
+}
+
+impl DismissReviewBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+            message: None,
+            priors: None,
+        }
+    }
+    /// Send the request to dismiss the review.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<PullReview>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (index, id) = (self.index, self.id);
+            let req = client
+                .post(format!(
+                    "repos/{owner}/{repo}/pulls/{index}/reviews/{id}/dismissals"
+                ))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UndismissReviewBuilder {
+    owner: String,
+    repo: String,
+    index: i64,
+    id: i64,
+}
+
+impl UndismissReviewBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+        }
+    }
+    /// Send the request to restore the dismissed review.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<PullReview>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (index, id) = (self.index, self.id);
+            let req = client
+                .post(format!(
+                    "repos/{owner}/{repo}/pulls/{index}/reviews/{id}/undismissals"
+                ))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -2,11 +2,7 @@ use build_it::Builder;
 use serde::Serialize;
 
 use crate::{
-    error::Result,
-    model::{
-        reviews::PullReview,
-    },
-    Client,
+    error::Result, model::reviews::PullReview, pagination::Paginated, path::segment, Executor,
 };
 
 #[derive(Debug, Clone, Serialize, Builder)]
@@ -40,15 +36,71 @@ impl GetReviewsBuilder {
         }
     }
     /// Sends the request to list all reviews for a pull request.
-    pub async fn send(&self, client: &Client) -> Result<Vec<PullReview>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<PullReview>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for GetReviewsBuilder {
+    type Item = PullReview;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
         let index = &self.index;
-        let req = client
-            .get(format!("repos/{owner}/{repo}/pulls/{index}/reviews"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+        format!("repos/{owner}/{repo}/pulls/{index}/reviews")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetReviewBuilder {
+    owner: String,
+    repo: String,
+    index: i64,
+    id: i64,
+}
+
+impl GetReviewBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+        }
+    }
+    /// Sends the request to get a single review of a pull request.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<PullReview>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (index, id) = (self.index, self.id);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/pulls/{index}/reviews/{id}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
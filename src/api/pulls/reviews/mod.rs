@@ -1,4 +1,11 @@
+pub mod comments;
+pub mod create;
+pub mod delete;
+pub mod dismiss;
 pub mod get;
+pub mod submit;
+
+use crate::model::reviews::ReviewStateType;
 
 pub struct Reviews {
     pub(crate) owner: String,
@@ -37,4 +44,206 @@ impl Reviews {
     pub fn get(&self, index: i64) -> get::GetReviewsBuilder {
         get::GetReviewsBuilder::new(&self.owner, &self.repo, index)
     }
+
+    /// Get a single [review](crate::model::reviews::PullReview) of a pull request by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_review() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let review = client
+    ///     .pulls("owner", "repo")
+    ///     .reviews()
+    ///     .get_by_id(1, 42)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get_by_id(&self, index: i64, id: i64) -> get::GetReviewBuilder {
+        get::GetReviewBuilder::new(&self.owner, &self.repo, index, id)
+    }
+
+    /// Create a [review](crate::model::reviews::PullReview) of a pull request, optionally with
+    /// line-level comments.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::reviews::{CreateReviewComment, ReviewStateType}};
+    /// # async fn create_review() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let review = client
+    ///     .pulls("owner", "repo")
+    ///     .reviews()
+    ///     .create(1)
+    ///     .body("Looks good, just one nit.")
+    ///     .event(ReviewStateType::Approved)
+    ///     .comment(CreateReviewComment::new_line("src/lib.rs", 12, "Typo here"))
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will approve pull request #1 with a comment on line 12 of `src/lib.rs`.
+    /// Leave out the event to create a pending review, and [submit](Self::submit) it later.
+    pub fn create(&self, index: i64) -> create::CreateReviewBuilder {
+        create::CreateReviewBuilder::new(&self.owner, &self.repo, index)
+    }
+
+    /// Submit a pending review with the given verdict.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::reviews::ReviewStateType};
+    /// # async fn submit_review() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let review = client
+    ///     .pulls("owner", "repo")
+    ///     .reviews()
+    ///     .submit(1, 42, ReviewStateType::RequestChanges)
+    ///     .body("Please add tests.")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn submit(
+        &self,
+        index: i64,
+        id: i64,
+        event: ReviewStateType,
+    ) -> submit::SubmitReviewBuilder {
+        submit::SubmitReviewBuilder::new(&self.owner, &self.repo, index, id, event)
+    }
+
+    /// Delete a review. Only pending reviews, or reviews of the authenticated user, can be deleted.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_review() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .pulls("owner", "repo")
+    ///     .reviews()
+    ///     .delete(1, 42)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, index: i64, id: i64) -> delete::DeleteReviewBuilder {
+        delete::DeleteReviewBuilder::new(&self.owner, &self.repo, index, id)
+    }
+
+    /// List the line-level [comments](crate::model::reviews::ReviewComment) of a review.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn review_comments() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let comments = client
+    ///     .pulls("owner", "repo")
+    ///     .reviews()
+    ///     .comments(1, 42)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn comments(&self, index: i64, id: i64) -> comments::ListReviewCommentsBuilder {
+        comments::ListReviewCommentsBuilder::new(&self.owner, &self.repo, index, id)
+    }
+
+    /// Get a single [comment](crate::model::reviews::ReviewComment) of a review.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn review_comment() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let comment = client
+    ///     .pulls("owner", "repo")
+    ///     .reviews()
+    ///     .get_comment(1, 42, 7)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get_comment(
+        &self,
+        index: i64,
+        id: i64,
+        comment: i64,
+    ) -> comments::GetReviewCommentBuilder {
+        comments::GetReviewCommentBuilder::new(&self.owner, &self.repo, index, id, comment)
+    }
+
+    /// Dismiss a review, so it no longer counts towards the pull request's approvals.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn dismiss_review() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let review = client
+    ///     .pulls("owner", "repo")
+    ///     .reviews()
+    ///     .dismiss(1, 42)
+    ///     .message("Outdated after the rewrite")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn dismiss(&self, index: i64, id: i64) -> dismiss::DismissReviewBuilder {
+        dismiss::DismissReviewBuilder::new(&self.owner, &self.repo, index, id)
+    }
+
+    /// Restore a dismissed review.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn undismiss_review() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let review = client
+    ///     .pulls("owner", "repo")
+    ///     .reviews()
+    ///     .undismiss(1, 42)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn undismiss(&self, index: i64, id: i64) -> dismiss::UndismissReviewBuilder {
+        dismiss::UndismissReviewBuilder::new(&self.owner, &self.repo, index, id)
+    }
 }
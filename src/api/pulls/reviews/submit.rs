@@ -0,0 +1,66 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::reviews::{PullReview, ReviewStateType},
+    path::segment,
+    Executor,
+};
+
+/// Options for submitting a pending review.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct SubmitReviewBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    index: i64,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    #[skip]
+    event: ReviewStateType,
+    /// The review's summary.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+}
+
+impl SubmitReviewBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        index: i64,
+        id: i64,
+        event: ReviewStateType,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            id,
+            event,
+            body: None,
+        }
+    }
+    /// Send the request to submit the review.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<PullReview>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (index, id) = (self.index, self.id);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/pulls/{index}/reviews/{id}"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::pulls::UpdateStyle, path::segment, Executor};
+
+/// Options for updating a pull request's head branch with the changes of its base branch.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct UpdatePullRequestBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    index: i64,
+    /// How to update the head branch. Defaults to [UpdateStyle::Merge].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<UpdateStyle>,
+}
+
+impl UpdatePullRequestBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, index: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            index,
+            style: None,
+        }
+    }
+    /// Send the request to update the pull request.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let index = self.index;
+            let req = client
+                .post(format!("repos/{owner}/{repo}/pulls/{index}/update"))
+                .query(self)
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{error::Result, model::user::User, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct ListAssigneesBuilder {
+    owner: String,
+    repo: String,
+}
+
+impl ListAssigneesBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+    /// Send the request to list the users that can be assigned to issues.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<User>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/assignees"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListReviewersBuilder {
+    owner: String,
+    repo: String,
+}
+
+impl ListReviewersBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+    /// Send the request to list the users that can be requested to review pull requests.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<User>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/reviewers"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
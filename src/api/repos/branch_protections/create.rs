@@ -0,0 +1,166 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::repos::BranchProtection, path::segment, Executor};
+
+/// Options for creating a branch protection rule.
+/// The only required field is the rule name. Fields that are not set use Gitea's defaults,
+/// which don't restrict anything.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateBranchProtectionBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    /// The branch name or glob pattern (like `release/*`) the rule applies to.
+    #[skip]
+    rule_name: String,
+    /// The priority of the rule if several rules match a branch. Higher values take precedence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<i64>,
+    /// Whether pushing to matching branches is allowed at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_push: Option<bool>,
+    /// Whether only the allowlisted users, teams and deploy keys may push.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_push_whitelist: Option<bool>,
+    /// The usernames of the users that may push.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    push_whitelist_usernames: Option<Vec<String>>,
+    /// The names of the teams that may push.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    push_whitelist_teams: Option<Vec<String>>,
+    /// Whether deploy keys with write access may push.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    push_whitelist_deploy_keys: Option<bool>,
+    /// Whether force-pushing to matching branches is allowed at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_force_push: Option<bool>,
+    /// Whether only the allowlisted users, teams and deploy keys may force-push.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_force_push_allowlist: Option<bool>,
+    /// The usernames of the users that may force-push.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    force_push_allowlist_usernames: Option<Vec<String>>,
+    /// The names of the teams that may force-push.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    force_push_allowlist_teams: Option<Vec<String>>,
+    /// Whether deploy keys with write access may force-push.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    force_push_allowlist_deploy_keys: Option<bool>,
+    /// Whether only the allowlisted users and teams may merge pull requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_merge_whitelist: Option<bool>,
+    /// The usernames of the users that may merge pull requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_whitelist_usernames: Option<Vec<String>>,
+    /// The names of the teams that may merge pull requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_whitelist_teams: Option<Vec<String>>,
+    /// Whether status checks must pass before pull requests can be merged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_status_check: Option<bool>,
+    /// The contexts of the status checks that must pass, like `ci/build`. Glob patterns are
+    /// allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_check_contexts: Option<Vec<String>>,
+    /// The number of approvals a pull request needs before it can be merged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_approvals: Option<i64>,
+    /// Whether only approvals of the allowlisted users and teams count.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_approvals_whitelist: Option<bool>,
+    /// The usernames of the users whose approvals count.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    approvals_whitelist_username: Option<Vec<String>>,
+    /// The names of the teams whose approvals count.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    approvals_whitelist_teams: Option<Vec<String>>,
+    /// Whether merging is blocked while changes are requested by an official reviewer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_on_rejected_reviews: Option<bool>,
+    /// Whether merging is blocked while official review requests are pending.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_on_official_review_requests: Option<bool>,
+    /// Whether merging is blocked while the head branch is behind the base branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_on_outdated_branch: Option<bool>,
+    /// Whether approvals are dismissed when new commits are pushed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dismiss_stale_approvals: Option<bool>,
+    /// Whether approvals of older commits don't count towards the required approvals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_stale_approvals: Option<bool>,
+    /// Whether all commits must be signed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    require_signed_commits: Option<bool>,
+    /// Semicolon-separated glob patterns of files that can't be changed, like `*.lock;docs/**`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    protected_file_patterns: Option<String>,
+    /// Semicolon-separated glob patterns of files that can be changed even by users who may not
+    /// push, like `CHANGELOG.md`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unprotected_file_patterns: Option<String>,
+    /// Whether administrators must follow the rule too when merging.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_admin_merge_override: Option<bool>,
+}
+
+impl CreateBranchProtectionBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, rule_name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            rule_name: rule_name.to_string(),
+            priority: None,
+            enable_push: None,
+            enable_push_whitelist: None,
+            push_whitelist_usernames: None,
+            push_whitelist_teams: None,
+            push_whitelist_deploy_keys: None,
+            enable_force_push: None,
+            enable_force_push_allowlist: None,
+            force_push_allowlist_usernames: None,
+            force_push_allowlist_teams: None,
+            force_push_allowlist_deploy_keys: None,
+            enable_merge_whitelist: None,
+            merge_whitelist_usernames: None,
+            merge_whitelist_teams: None,
+            enable_status_check: None,
+            status_check_contexts: None,
+            required_approvals: None,
+            enable_approvals_whitelist: None,
+            approvals_whitelist_username: None,
+            approvals_whitelist_teams: None,
+            block_on_rejected_reviews: None,
+            block_on_official_review_requests: None,
+            block_on_outdated_branch: None,
+            dismiss_stale_approvals: None,
+            ignore_stale_approvals: None,
+            require_signed_commits: None,
+            protected_file_patterns: None,
+            unprotected_file_patterns: None,
+            block_admin_merge_override: None,
+        }
+    }
+    /// Send the request to create the branch protection rule.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<BranchProtection>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/branch_protections"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{error::Result, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct DeleteBranchProtectionBuilder {
+    owner: String,
+    repo: String,
+    name: String,
+}
+
+impl DeleteBranchProtectionBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            name: name.to_string(),
+        }
+    }
+    /// Send the request to delete the branch protection rule.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let name = segment(&self.name);
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/branch_protections/{name}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
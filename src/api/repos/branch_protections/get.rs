@@ -0,0 +1,35 @@
+use crate::{error::Result, model::repos::BranchProtection, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct GetBranchProtectionBuilder {
+    owner: String,
+    repo: String,
+    name: String,
+}
+
+impl GetBranchProtectionBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            name: name.to_string(),
+        }
+    }
+    /// Send the request to get the branch protection rule.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<BranchProtection>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let name = segment(&self.name);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/branch_protections/{name}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{error::Result, model::repos::BranchProtection, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct ListBranchProtectionsBuilder {
+    owner: String,
+    repo: String,
+}
+
+impl ListBranchProtectionsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+    /// Send the request to list the branch protection rules.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<BranchProtection>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/branch_protections"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
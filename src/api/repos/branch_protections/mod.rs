@@ -0,0 +1,140 @@
+pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod list;
+
+/// The [BranchProtections] struct provides methods for managing a repository's branch
+/// protection rules.
+pub struct BranchProtections {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl BranchProtections {
+    /// Lists the repository's [branch protection rules](crate::model::repos::BranchProtection).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_branch_protections() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let rules = client
+    ///     .repos("owner", "repo")
+    ///     .branch_protections()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> list::ListBranchProtectionsBuilder {
+        list::ListBranchProtectionsBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Gets a branch protection rule by its name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_branch_protection() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let rule = client
+    ///     .repos("owner", "repo")
+    ///     .branch_protections()
+    ///     .get("main")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, name: impl ToString) -> get::GetBranchProtectionBuilder {
+        get::GetBranchProtectionBuilder::new(&self.owner, &self.repo, name)
+    }
+
+    /// Creates a branch protection rule for the branches matching a name or glob pattern.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_branch_protection() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let rule = client
+    ///     .repos("owner", "repo")
+    ///     .branch_protections()
+    ///     .create("main")
+    ///     .enable_push(true)
+    ///     .enable_push_whitelist(true)
+    ///     .push_whitelist_teams(vec!["maintainers".to_string()])
+    ///     .required_approvals(2)
+    ///     .enable_status_check(true)
+    ///     .status_check_contexts(vec!["ci/build".to_string()])
+    ///     .dismiss_stale_approvals(true)
+    ///     .block_on_outdated_branch(true)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will only allow the `maintainers` team to push to `main` directly, and require two
+    /// fresh approvals, a passing `ci/build` check and an up-to-date branch to merge pull requests.
+    pub fn create(&self, rule_name: impl ToString) -> create::CreateBranchProtectionBuilder {
+        create::CreateBranchProtectionBuilder::new(&self.owner, &self.repo, rule_name)
+    }
+
+    /// Edits a branch protection rule.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_branch_protection() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let rule = client
+    ///     .repos("owner", "repo")
+    ///     .branch_protections()
+    ///     .edit("main")
+    ///     .required_approvals(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self, name: impl ToString) -> edit::EditBranchProtectionBuilder {
+        edit::EditBranchProtectionBuilder::new(&self.owner, &self.repo, name)
+    }
+
+    /// Deletes a branch protection rule.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_branch_protection() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .branch_protections()
+    ///     .delete("main")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, name: impl ToString) -> delete::DeleteBranchProtectionBuilder {
+        delete::DeleteBranchProtectionBuilder::new(&self.owner, &self.repo, name)
+    }
+}
@@ -1,7 +1,13 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Branch, Client};
+use crate::{
+    error::Result,
+    model::repos::Branch,
+    pagination::Paginated,
+    path::{segment, segments},
+    Executor,
+};
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -69,15 +75,35 @@ impl ListBranchesBuilder {
         }
     }
     /// Sends the request to list a repository's branches.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Branch>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .get(format!("repos/{owner}/{repo}/branches"))
-            .query(&self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Branch>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(&self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListBranchesBuilder {
+    type Item = Branch;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        format!("repos/{owner}/{repo}/branches")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
 
@@ -91,15 +117,18 @@ impl CreateBranchBuilder {
         }
     }
     /// Sends the request to create a branch.
-    pub async fn send(&self, client: &Client) -> Result<Branch> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .post(format!("repos/{owner}/{repo}/branches"))
-            .json(&self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Branch>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/branches"))
+                .json(&self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -112,17 +141,18 @@ impl GetBranchBuilder {
         }
     }
     /// Sends the request to get a branch.
-    pub async fn send(&self, client: &Client) -> Result<Branch> {
-        let Self {
-            owner,
-            repo,
-            branch,
-        } = self;
-        let req = client
-            .get(format!("repos/{owner}/{repo}/branches/{branch}"))
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Branch>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let branch = segments(&self.branch);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/branches/{branch}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -135,16 +165,17 @@ impl DeleteBranchBuilder {
         }
     }
     /// Sends the request to get a branch.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let Self {
-            owner,
-            repo,
-            branch,
-        } = self;
-        let req = client
-            .delete(format!("repos/{owner}/{repo}/branches/{branch}"))
-            .build()?;
-        let _ = client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let branch = segments(&self.branch);
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/branches/{branch}"))
+                .build()?;
+            let _ = client.make_request(req).await?;
+            Ok(())
+        })
     }
 }
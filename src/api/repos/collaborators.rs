@@ -0,0 +1,340 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::{Result, TeatimeErrorKind},
+    model::{repos::RepoCollaboratorPermission, team::Permission, user::User},
+    pagination::Paginated,
+    path::segment,
+    Executor,
+};
+
+/// The [Collaborators] struct provides methods for managing who has access to a repository.
+pub struct Collaborators {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl Collaborators {
+    /// Lists the repository's collaborators.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_collaborators() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let collaborators = client
+    ///     .repos("owner", "repo")
+    ///     .collaborators()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListCollaboratorsBuilder {
+        ListCollaboratorsBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Checks whether a user is a collaborator of the repository.
+    /// Users with access through a team or as the owner are not collaborators.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn is_collaborator() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let is_collaborator = client
+    ///     .repos("owner", "repo")
+    ///     .collaborators()
+    ///     .is_collaborator("alice")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn is_collaborator(&self, username: impl ToString) -> IsCollaboratorBuilder {
+        IsCollaboratorBuilder::new(&self.owner, &self.repo, username)
+    }
+
+    /// Adds a user as a collaborator, or changes the permission of an existing collaborator.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::team::Permission};
+    /// # async fn add_collaborator() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .collaborators()
+    ///     .add("alice")
+    ///     .permission(Permission::Read)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add(&self, username: impl ToString) -> AddCollaboratorBuilder {
+        AddCollaboratorBuilder::new(&self.owner, &self.repo, username)
+    }
+
+    /// Removes a collaborator from the repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_collaborator() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .collaborators()
+    ///     .remove("alice")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn remove(&self, username: impl ToString) -> RemoveCollaboratorBuilder {
+        RemoveCollaboratorBuilder::new(&self.owner, &self.repo, username)
+    }
+
+    /// Gets the permission a user has in the repository, whether they're a collaborator or not.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_permission() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let permission = client
+    ///     .repos("owner", "repo")
+    ///     .collaborators()
+    ///     .permission("alice")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn permission(&self, username: impl ToString) -> GetPermissionBuilder {
+        GetPermissionBuilder::new(&self.owner, &self.repo, username)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListCollaboratorsBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListCollaboratorsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the collaborators.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<User>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListCollaboratorsBuilder {
+    type Item = User;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        format!("repos/{owner}/{repo}/collaborators")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IsCollaboratorBuilder {
+    owner: String,
+    repo: String,
+    username: String,
+}
+
+impl IsCollaboratorBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, username: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            username: username.to_string(),
+        }
+    }
+    /// Send the request to check whether the user is a collaborator.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<bool>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let username = segment(&self.username);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/collaborators/{username}"))
+                .build()?;
+            match client.make_request(req).await {
+                Ok(_) => Ok(true),
+                Err(e) if e.kind == TeatimeErrorKind::NotFound => Ok(false),
+                Err(e) => Err(e),
+            }
+        })
+    }
+}
+
+/// Options for adding a collaborator.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct AddCollaboratorBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    username: String,
+    /// The collaborator's permission: [Read](Permission::Read), [Write](Permission::Write) or
+    /// [Admin](Permission::Admin). Defaults to write.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permission: Option<Permission>,
+}
+
+impl AddCollaboratorBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, username: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            username: username.to_string(),
+            permission: None,
+        }
+    }
+    /// Send the request to add the collaborator.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let username = segment(&self.username);
+            let req = client
+                .put(format!("repos/{owner}/{repo}/collaborators/{username}"))
+                .json(self)
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveCollaboratorBuilder {
+    owner: String,
+    repo: String,
+    username: String,
+}
+
+impl RemoveCollaboratorBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, username: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            username: username.to_string(),
+        }
+    }
+    /// Send the request to remove the collaborator.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let username = segment(&self.username);
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/collaborators/{username}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetPermissionBuilder {
+    owner: String,
+    repo: String,
+    username: String,
+}
+
+impl GetPermissionBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, username: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            username: username.to_string(),
+        }
+    }
+    /// Send the request to get the user's permission.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<RepoCollaboratorPermission>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let username = segment(&self.username);
+            let req = client
+                .get(format!(
+                    "repos/{owner}/{repo}/collaborators/{username}/permission"
+                ))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Commit};
+use crate::{error::Result, model::repos::Commit, pagination::Paginated, path::segment, Executor};
 
 /// Options for getting a list of commits from a repository.
 /// All fields are optional.
@@ -65,15 +65,34 @@ impl GetCommitsBuilder {
     }
 
     /// Send the request to get the commits.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Commit>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Commit>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
 
-        let req = client
-            .get(format!("repos/{owner}/{repo}/commits"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+impl Paginated for GetCommitsBuilder {
+    type Item = Commit;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        format!("repos/{owner}/{repo}/commits")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     model::repos::{CommitDateOptions, EntryMutation, Identity},
-    Result,
+    path::{segment, segments},
+    Executor, Result,
 };
 
 #[derive(Debug, Serialize, Deserialize, Builder)]
@@ -66,18 +67,24 @@ impl DeleteFileRepoBuilder {
     }
 
     /// Send the request to delete file from given repository.
-    pub async fn send(&self, client: &crate::Client) -> Result<EntryMutation> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let filepath = &self.filepath;
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<EntryMutation>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let filepath = segments(&self.filepath);
 
-        let req = client
-            .delete(format!("repos/{owner}/{repo}/contents/{filepath}"))
-            .json(self)
-            .build()?;
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/contents/{filepath}"))
+                .json(self)
+                .build()?;
 
-        let res = client.make_request(req).await?;
+            let res = client.make_request(req).await?;
 
-        client.parse_response(res).await
+            client.parse_response(res).await
+        })
     }
 }
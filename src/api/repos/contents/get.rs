@@ -2,7 +2,11 @@ use build_it::Builder;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::{model::repos::Entry, Result};
+use crate::{
+    model::repos::Entry,
+    path::{segment, segments},
+    Executor, Result,
+};
 
 #[derive(Debug, Serialize, Deserialize, Builder)]
 #[build_it(into)]
@@ -36,23 +40,26 @@ impl GetContentsRepoBuilder {
     }
 
     /// Send the request to fetch given repository's file path contents.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Entry>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let filepath = &self.filepath;
-        let req = client
-            .get(format!("repos/{owner}/{repo}/contents/{filepath}"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Entry>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let filepath = segments(&self.filepath);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/contents/{filepath}"))
+                .query(self)
+                .build()?;
+            let res = client.make_request(req).await?;
 
-        let res_body: Value = res.json().await?;
+            let res_body: Value = res.json().await?;
 
-        // Response can either be Entry or Entry[], put in Vec<> to handle both cases
-        let entries = serde_json::from_value::<Vec<Entry>>(res_body.clone()).or_else(|_| {
-            serde_json::from_value(res_body.clone()).map(|single_entry| vec![single_entry])
-        })?;
+            // Response can either be Entry or Entry[], put in Vec<> to handle both cases
+            let entries = serde_json::from_value::<Vec<Entry>>(res_body.clone()).or_else(|_| {
+                serde_json::from_value(res_body.clone()).map(|single_entry| vec![single_entry])
+            })?;
 
-        Ok(entries)
+            Ok(entries)
+        })
     }
 }
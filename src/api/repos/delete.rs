@@ -1,3 +1,5 @@
+use crate::path::segment;
+use crate::Executor;
 use crate::Result;
 
 #[derive(Debug)]
@@ -15,10 +17,14 @@ impl DeleteRepoBuilder {
     }
 
     /// Send the request to delete the repository.
-    pub async fn send(&self, client: &crate::Client) -> Result<()> {
-        let DeleteRepoBuilder { owner, repo } = self;
-        let req = client.delete(format!("repos/{owner}/{repo}")).build()?;
-        client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client.delete(format!("repos/{owner}/{repo}")).build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
     }
 }
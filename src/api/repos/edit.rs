@@ -4,6 +4,8 @@ use serde::Serialize;
 use crate::{
     error::Result,
     model::repos::{ExternalTracker, ExternalWiki, Repository},
+    path::segment,
+    Executor,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Builder)]
@@ -123,14 +125,17 @@ impl EditRepoBuilder {
         }
     }
     /// Send the request to edit the repository.
-    pub async fn send(&self, client: &crate::Client) -> Result<Repository> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .patch(format!("repos/{owner}/{repo}"))
-            .json(&self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Repository>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .patch(format!("repos/{owner}/{repo}"))
+                .json(&self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
@@ -1,7 +1,9 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Repository, Client};
+use crate::{
+    error::Result, model::repos::Repository, pagination::Paginated, path::segment, Executor,
+};
 
 /// Options for forking a repository.
 /// All fields are optional.
@@ -35,15 +37,18 @@ impl CreateForkBuilder {
         }
     }
     /// Send the request to fork the repository.
-    pub async fn send(&self, client: &Client) -> Result<Repository> {
-        let owner = &self.owner;
-        let repo = &self.repo;
-        let req = client
-            .post(format!("repos/{owner}/{repo}/forks"))
-            .json(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Repository>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/forks"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -81,15 +86,37 @@ impl ListForksBuilder {
         }
     }
     /// Send the request to list the forks.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Repository>> {
-        let owner = &self.owner;
-        let repo = &self.repo;
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<Repository>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
 
-        let req = client
-            .get(format!("repos/{owner}/{repo}/forks"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+impl Paginated for ListForksBuilder {
+    type Item = Repository;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        format!("repos/{owner}/{repo}/forks")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
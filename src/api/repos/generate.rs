@@ -1,7 +1,7 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Repository};
+use crate::{error::Result, model::repos::Repository, path::segment, Executor};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Builder)]
 #[build_it(into)]
@@ -67,14 +67,17 @@ impl GenerateRepoBuilder {
         }
     }
     /// Send the request to generate the repository.
-    pub async fn send(&self, client: &crate::Client) -> Result<Repository> {
-        let template_owner = &self.template_owner;
-        let template_repo = &self.template_repo;
-        let req = client
-            .post(format!("repos/{template_owner}/{template_repo}/generate"))
-            .json(&self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Repository>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let template_owner = segment(&self.template_owner);
+            let template_repo = segment(&self.template_repo);
+            let req = client
+                .post(format!("repos/{template_owner}/{template_repo}/generate"))
+                .json(&self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
@@ -1,4 +1,4 @@
-use crate::{error::Result, model::repos::Repository};
+use crate::{error::Result, model::repos::Repository, path::segment, Executor};
 
 #[derive(Debug)]
 pub struct GetRepoBuilder {
@@ -16,10 +16,14 @@ impl GetRepoBuilder {
     /// Send the request to get the repository.
     /// This will return a [Repository] object if the repository exists and is visible to the
     /// currently authenticated user.
-    pub async fn send(&self, client: &crate::Client) -> Result<Repository> {
-        let GetRepoBuilder { owner, repo } = self;
-        let req = client.get(format!("repos/{owner}/{repo}")).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Repository>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client.get(format!("repos/{owner}/{repo}")).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
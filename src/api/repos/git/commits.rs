@@ -0,0 +1,117 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::{diff::Diff, pulls::DiffType, repos::Commit},
+    path::segment,
+    Executor,
+};
+
+/// Options for getting a single commit.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct GetGitCommitBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    sha: String,
+    /// Whether to include the `stat` field in the response.
+    /// Disable to speed-up the response.
+    /// Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stat: Option<bool>,
+    /// Whether to include the `verification` field in the response.
+    /// Disable to speed-up the response.
+    /// Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification: Option<bool>,
+    /// Whether to include the `files` field in the response.
+    /// Disable to speed-up the response.
+    /// Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<bool>,
+}
+
+impl GetGitCommitBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, sha: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            sha: sha.to_string(),
+            stat: None,
+            verification: None,
+            files: None,
+        }
+    }
+    /// Send the request to get the commit.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Commit>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let sha = segment(&self.sha);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/git/commits/{sha}"))
+                .query(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetCommitDiffBuilder {
+    owner: String,
+    repo: String,
+    sha: String,
+    diff_type: DiffType,
+}
+
+impl GetCommitDiffBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        sha: impl ToString,
+        diff_type: DiffType,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            sha: sha.to_string(),
+            diff_type,
+        }
+    }
+    /// Send the request to get the raw diff or patch.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<String>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let sha = segment(&self.sha);
+            let diff_type = self.diff_type.as_str();
+            let req = client
+                .get(format!(
+                    "repos/{owner}/{repo}/git/commits/{sha}.{diff_type}"
+                ))
+                .build()?;
+            let res = client.make_request(req).await?;
+            Ok(res.text().await?)
+        })
+    }
+    /// Send the request and parse the diff or patch into a [Diff].
+    pub fn send_parsed<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Diff>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let text = Self::send(self, client).await?;
+            Ok(Diff::parse(&text))
+        })
+    }
+}
@@ -0,0 +1,238 @@
+use crate::model::pulls::DiffType;
+
+pub mod blobs;
+pub mod commits;
+pub mod notes;
+pub mod refs;
+pub mod tags;
+pub mod trees;
+
+/// The [GitData] struct provides methods for reading a repository's git objects directly:
+/// trees, blobs, references, tags, commits and notes.
+pub struct GitData {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl GitData {
+    /// Gets a [tree](crate::model::git::GitTree) by its SHA. A commit SHA or a branch or tag name
+    /// also works and returns the root tree of that commit.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_tree() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let tree = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .tree("main")
+    ///     .recursive(true)
+    ///     .per_page(1000)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will list all files of the repository at the head of `main`, 1000 at a time.
+    /// Request the next [page](trees::GetTreeBuilder::page) while the tree is
+    /// [truncated](crate::model::git::GitTree::truncated).
+    pub fn tree(&self, sha: impl ToString) -> trees::GetTreeBuilder {
+        trees::GetTreeBuilder::new(&self.owner, &self.repo, sha)
+    }
+
+    /// Gets a [blob](crate::model::git::GitBlob), the contents of a file, by its SHA.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_blob() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let blob = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .blob("e69de29bb2d1d6434b8b29ae775ad8c2e48c5391")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// Use [GitBlob::decode](crate::model::git::GitBlob::decode) to get the file's contents.
+    pub fn blob(&self, sha: impl ToString) -> blobs::GetBlobBuilder {
+        blobs::GetBlobBuilder::new(&self.owner, &self.repo, sha)
+    }
+
+    /// Lists all [references](crate::model::git::Reference) of the repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_refs() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let refs = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .refs()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn refs(&self) -> refs::ListRefsBuilder {
+        refs::ListRefsBuilder::new(&self.owner, &self.repo, None)
+    }
+
+    /// Gets the [references](crate::model::git::Reference) whose name starts with the given prefix,
+    /// like `heads/main` or `tags`. The `refs/` prefix is optional.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_ref() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let refs = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .get_ref("heads/main")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// Gitea matches by prefix, so this returns a list: `heads/main` also matches
+    /// `heads/main-backup`.
+    pub fn get_ref(&self, prefix: impl ToString) -> refs::ListRefsBuilder {
+        refs::ListRefsBuilder::new(&self.owner, &self.repo, Some(prefix.to_string()))
+    }
+
+    /// Gets an [annotated tag](crate::model::git::AnnotatedTag) by the SHA of its tag object.
+    /// The SHA is the one a tag's [Reference](crate::model::git::Reference) points to.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_tag() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let tag = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .tag("a1b2c3d4")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn tag(&self, sha: impl ToString) -> tags::GetAnnotatedTagBuilder {
+        tags::GetAnnotatedTagBuilder::new(&self.owner, &self.repo, sha)
+    }
+
+    /// Gets a single [commit](crate::model::repos::Commit) by its SHA or a ref name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_commit() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let commit = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .commit("a1b2c3d4")
+    ///     .files(false)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn commit(&self, sha: impl ToString) -> commits::GetGitCommitBuilder {
+        commits::GetGitCommitBuilder::new(&self.owner, &self.repo, sha)
+    }
+
+    /// Gets the changes of a commit as a unified diff.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn commit_diff() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let diff = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .commit_diff("a1b2c3d4")
+    ///     .send_parsed(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// Use [send](commits::GetCommitDiffBuilder::send) to get the raw text instead of a
+    /// [Diff](crate::model::diff::Diff).
+    pub fn commit_diff(&self, sha: impl ToString) -> commits::GetCommitDiffBuilder {
+        commits::GetCommitDiffBuilder::new(&self.owner, &self.repo, sha, DiffType::Diff)
+    }
+
+    /// Gets a commit as a patch, like `git format-patch`.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn commit_patch() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let patch = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .commit_patch("a1b2c3d4")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn commit_patch(&self, sha: impl ToString) -> commits::GetCommitDiffBuilder {
+        commits::GetCommitDiffBuilder::new(&self.owner, &self.repo, sha, DiffType::Patch)
+    }
+
+    /// Gets the git [note](crate::model::git::Note) attached to a commit.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_note() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let note = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .note("a1b2c3d4")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn note(&self, sha: impl ToString) -> notes::GetNoteBuilder {
+        notes::GetNoteBuilder::new(&self.owner, &self.repo, sha)
+    }
+}
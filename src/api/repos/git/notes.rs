@@ -0,0 +1,57 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::git::Note, path::segment, Executor};
+
+/// Options for getting the note of a commit.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct GetNoteBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    sha: String,
+    /// Whether to include the `verification` field of the commit.
+    /// Disable to speed-up the response.
+    /// Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification: Option<bool>,
+    /// Whether to include the `files` field of the commit.
+    /// Disable to speed-up the response.
+    /// Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<bool>,
+}
+
+impl GetNoteBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, sha: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            sha: sha.to_string(),
+            verification: None,
+            files: None,
+        }
+    }
+    /// Send the request to get the note.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Note>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let sha = segment(&self.sha);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/git/notes/{sha}"))
+                .query(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
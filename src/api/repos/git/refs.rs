@@ -0,0 +1,44 @@
+use crate::{
+    error::Result,
+    model::git::Reference,
+    path::{segment, segments},
+    Executor,
+};
+
+#[derive(Debug, Clone)]
+pub struct ListRefsBuilder {
+    owner: String,
+    repo: String,
+    prefix: Option<String>,
+}
+
+impl ListRefsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, prefix: Option<String>) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            prefix,
+        }
+    }
+    /// Send the request to list the references.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<Reference>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let path = match &self.prefix {
+                Some(prefix) => {
+                    let prefix = segments(prefix.trim_start_matches("refs/"));
+                    format!("repos/{owner}/{repo}/git/refs/{prefix}")
+                }
+                None => format!("repos/{owner}/{repo}/git/refs"),
+            };
+            let req = client.get(path).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{error::Result, model::git::AnnotatedTag, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct GetAnnotatedTagBuilder {
+    owner: String,
+    repo: String,
+    sha: String,
+}
+
+impl GetAnnotatedTagBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, sha: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            sha: sha.to_string(),
+        }
+    }
+    /// Send the request to get the tag object.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<AnnotatedTag>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let sha = segment(&self.sha);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/git/tags/{sha}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
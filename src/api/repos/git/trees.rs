@@ -0,0 +1,57 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::git::GitTree, path::segment, Executor};
+
+/// Options for getting a tree.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct GetTreeBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    sha: String,
+    /// Whether to list the entries of all subtrees too.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recursive: Option<bool>,
+    /// Page number of entries to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Number of entries per page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<i64>,
+}
+
+impl GetTreeBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, sha: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            sha: sha.to_string(),
+            recursive: None,
+            page: None,
+            per_page: None,
+        }
+    }
+    /// Send the request to get the tree.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<GitTree>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let sha = segment(&self.sha);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/git/trees/{sha}"))
+                .query(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
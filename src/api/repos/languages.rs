@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use crate::{error::Result, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct GetLanguagesBuilder {
+    owner: String,
+    repo: String,
+}
+
+impl GetLanguagesBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+    /// Send the request to get the languages.
+    /// This will return a map of language names to the number of bytes written in them.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<HashMap<String, i64>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/languages"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
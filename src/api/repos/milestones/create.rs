@@ -0,0 +1,64 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::{
+        issues::{Milestone, StateType},
+        Timestamp,
+    },
+    path::segment,
+    Executor,
+};
+
+/// Options for creating a milestone.
+/// The only required field is the title.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateMilestoneBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    /// The title of the milestone.
+    #[skip]
+    title: String,
+    /// The description of the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// The due date of the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<Timestamp>,
+    /// The state of the milestone. Defaults to open.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<StateType>,
+}
+
+impl CreateMilestoneBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, title: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            title: title.to_string(),
+            description: None,
+            due_on: None,
+            state: None,
+        }
+    }
+    /// Send the request to create the milestone.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Milestone>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/milestones"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
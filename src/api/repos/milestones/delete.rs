@@ -0,0 +1,34 @@
+use crate::error::Result;
+use crate::path::segment;
+use crate::Executor;
+
+#[derive(Debug, Clone)]
+pub struct DeleteMilestoneBuilder {
+    owner: String,
+    repo: String,
+    id: i64,
+}
+
+impl DeleteMilestoneBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+        }
+    }
+    /// Send the request to delete the milestone.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/milestones/{id}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
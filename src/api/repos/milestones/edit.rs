@@ -0,0 +1,69 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::{
+        issues::{Milestone, StateType},
+        Timestamp,
+    },
+    path::segment,
+    Executor,
+};
+
+/// Options for editing a milestone.
+/// Fields that are not set are left unchanged.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditMilestoneBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// The title of the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    /// The description of the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// The due date of the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<Timestamp>,
+    /// The state of the milestone. Set this to close or reopen the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<StateType>,
+}
+
+impl EditMilestoneBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            title: None,
+            description: None,
+            due_on: None,
+            state: None,
+        }
+    }
+    /// Send the request to edit the milestone.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Milestone>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .patch(format!("repos/{owner}/{repo}/milestones/{id}"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use crate::error::Result;
+use crate::model::issues::Milestone;
+use crate::path::segment;
+use crate::Executor;
+
+#[derive(Debug, Clone)]
+pub struct GetMilestoneBuilder {
+    owner: String,
+    repo: String,
+    id: i64,
+}
+
+impl GetMilestoneBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+        }
+    }
+    /// Send the request to get the milestone.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Milestone>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .get(format!("repos/{owner}/{repo}/milestones/{id}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,186 @@
+use crate::model::issues::StateType;
+
+pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod list;
+
+/// The [Milestones] struct provides methods for managing a repository's milestones.
+pub struct Milestones {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl Milestones {
+    /// Lists the repository's milestones.
+    /// Only open milestones are returned unless a [state](list::ListMilestonesBuilder::state) is
+    /// set.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::issues::State};
+    /// # async fn list_milestones() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let milestones = client
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .list()
+    ///     .state(State::All)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> list::ListMilestonesBuilder {
+        list::ListMilestonesBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Gets a milestone by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_milestone() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let milestone = client
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, id: i64) -> get::GetMilestoneBuilder {
+        get::GetMilestoneBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Creates a milestone.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_milestone() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let milestone = client
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .create("Sprint 12")
+    ///     .description("Second half of March")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create(&self, title: impl ToString) -> create::CreateMilestoneBuilder {
+        create::CreateMilestoneBuilder::new(&self.owner, &self.repo, title)
+    }
+
+    /// Edits a milestone.
+    /// Fields that are not set are left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_milestone() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let milestone = client
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .edit(1)
+    ///     .title("Sprint 13")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self, id: i64) -> edit::EditMilestoneBuilder {
+        edit::EditMilestoneBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Closes a milestone.
+    /// This is a shortcut for [edit](Self::edit) with the state set to closed, other fields can
+    /// be changed at the same time.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn close_milestone() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let milestone = client
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .close(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn close(&self, id: i64) -> edit::EditMilestoneBuilder {
+        self.edit(id).state(StateType::Closed)
+    }
+
+    /// Reopens a closed milestone.
+    /// This is a shortcut for [edit](Self::edit) with the state set to open.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn reopen_milestone() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let milestone = client
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .reopen(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn reopen(&self, id: i64) -> edit::EditMilestoneBuilder {
+        self.edit(id).state(StateType::Open)
+    }
+
+    /// Deletes a milestone.
+    /// Issues and pull requests in the milestone are kept, but no longer belong to a milestone.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_milestone() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .delete(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, id: i64) -> delete::DeleteMilestoneBuilder {
+        delete::DeleteMilestoneBuilder::new(&self.owner, &self.repo, id)
+    }
+}
@@ -1,4 +1,8 @@
+pub mod activities;
+pub mod assignees;
+pub mod branch_protections;
 pub mod branches;
+pub mod collaborators;
 pub mod commits;
 pub mod contents;
 pub mod delete;
@@ -6,6 +10,17 @@ pub mod edit;
 pub mod forks;
 pub mod generate;
 pub mod get;
+pub mod git;
+pub mod languages;
+pub mod milestones;
+pub mod releases;
+pub mod statuses;
+pub mod tag_protections;
+pub mod tags;
+pub mod teams;
+pub mod topics;
+
+use crate::path::segment;
 
 /// The [Repos] struct provides methods for interacting with repositories.
 pub struct Repos {
@@ -341,4 +356,456 @@ impl Repos {
             repo: self.repo.clone(),
         }
     }
+
+    /// Methods for managing the repository's releases and their assets.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn latest_release() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let release = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .latest()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn releases(&self) -> releases::Releases {
+        releases::Releases {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Methods for managing the repository's labels.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_labels() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let labels = client
+    ///     .repos("owner", "repo")
+    ///     .labels()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn labels(&self) -> crate::api::labels::Labels {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        crate::api::labels::Labels {
+            path: format!("repos/{owner}/{repo}/labels"),
+        }
+    }
+
+    /// Methods for managing the repository's milestones.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_milestones() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let milestones = client
+    ///     .repos("owner", "repo")
+    ///     .milestones()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn milestones(&self) -> milestones::Milestones {
+        milestones::Milestones {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Methods for managing the repository's webhooks.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_repo_hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn hooks(&self) -> crate::api::hooks::Hooks {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        crate::api::hooks::Hooks {
+            path: format!("repos/{owner}/{repo}/hooks"),
+        }
+    }
+
+    /// Triggers a test delivery of a repository webhook.
+    /// The delivery uses a push event payload for the latest commit of the default branch, or of
+    /// the branch or tag set with [refs](crate::api::hooks::test::TestHookBuilder::refs).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn test_hook() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .test_hook(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn test_hook(&self, id: i64) -> crate::api::hooks::test::TestHookBuilder {
+        crate::api::hooks::test::TestHookBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Methods for managing the repository's server-side git hooks.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_git_hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .repos("owner", "repo")
+    ///     .git_hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn git_hooks(&self) -> crate::api::hooks::GitHooks {
+        crate::api::hooks::GitHooks {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Methods for reporting and reading commit statuses, like CI results.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn combined_status() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let status = client
+    ///     .repos("owner", "repo")
+    ///     .statuses()
+    ///     .combined("main")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn statuses(&self) -> statuses::Statuses {
+        statuses::Statuses {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Methods for reading the repository's git objects, like trees, blobs and references.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_tree() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let tree = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .tree("main")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn git(&self) -> git::GitData {
+        git::GitData {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Methods for managing the repository's tags.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_tags() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let tags = client
+    ///     .repos("owner", "repo")
+    ///     .tags()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn tags(&self) -> tags::Tags {
+        tags::Tags {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Methods for managing the repository's tag protection rules.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_tag_protections() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let protections = client
+    ///     .repos("owner", "repo")
+    ///     .tag_protections()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn tag_protections(&self) -> tag_protections::TagProtections {
+        tag_protections::TagProtections {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Methods for managing the repository's branch protection rules.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_branch_protections() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let rules = client
+    ///     .repos("owner", "repo")
+    ///     .branch_protections()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn branch_protections(&self) -> branch_protections::BranchProtections {
+        branch_protections::BranchProtections {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Methods for managing the repository's collaborators and checking users' permissions.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_collaborators() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let collaborators = client
+    ///     .repos("owner", "repo")
+    ///     .collaborators()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn collaborators(&self) -> collaborators::Collaborators {
+        collaborators::Collaborators {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Lists the users that can be assigned to the repository's issues and pull requests.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_assignees() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let assignees = client
+    ///     .repos("owner", "repo")
+    ///     .list_assignees()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_assignees(&self) -> assignees::ListAssigneesBuilder {
+        assignees::ListAssigneesBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Lists the users that can be requested to review the repository's pull requests.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_reviewers() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let reviewers = client
+    ///     .repos("owner", "repo")
+    ///     .list_reviewers()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_reviewers(&self) -> assignees::ListReviewersBuilder {
+        assignees::ListReviewersBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Lists the teams with access to the repository.
+    /// Only repositories owned by an organization have teams.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_repo_teams() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let teams = client
+    ///     .repos("owner", "repo")
+    ///     .list_teams()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_teams(&self) -> teams::ListRepoTeamsBuilder {
+        teams::ListRepoTeamsBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Methods for managing the repository's topics.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn repo_topics() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let topics = client
+    ///     .repos("owner", "repo")
+    ///     .topics()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn topics(&self) -> topics::Topics {
+        topics::Topics {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+
+    /// Gets the languages used in the repository, mapped to the number of bytes of code
+    /// written in them.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_languages() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let languages = client
+    ///     .repos("owner", "repo")
+    ///     .languages()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn languages(&self) -> languages::GetLanguagesBuilder {
+        languages::GetLanguagesBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Lists the repository's activity feed, like pushes, new issues and merged pull requests,
+    /// newest first.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_activity_feeds() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let activities = client
+    ///     .repos("owner", "repo")
+    ///     .activity_feeds()
+    ///     .date("2024-06-01")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn activity_feeds(&self) -> activities::ListActivityFeedsBuilder {
+        activities::ListActivityFeedsBuilder::new(&self.owner, &self.repo)
+    }
 }
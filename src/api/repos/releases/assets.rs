@@ -0,0 +1,290 @@
+use build_it::Builder;
+use bytes::Bytes;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use reqwest::multipart::{Form, Part};
+use reqwest::{Body, Response};
+use serde::Serialize;
+
+use crate::{error::Result, model::issues::Attachment, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct ListReleaseAssetsBuilder {
+    owner: String,
+    repo: String,
+    release_id: i64,
+}
+
+impl ListReleaseAssetsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, release_id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            release_id,
+        }
+    }
+    /// Send the request to list the release's assets.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<Attachment>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.release_id;
+            let req = client
+                .get(format!("repos/{owner}/{repo}/releases/{id}/assets"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetReleaseAssetBuilder {
+    owner: String,
+    repo: String,
+    release_id: i64,
+    asset_id: i64,
+}
+
+impl GetReleaseAssetBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, release_id: i64, asset_id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            release_id,
+            asset_id,
+        }
+    }
+    /// Send the request to get the asset's metadata.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Attachment>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (id, asset) = (self.release_id, self.asset_id);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/releases/{id}/assets/{asset}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+/// Options for editing a release asset.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditReleaseAssetBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    release_id: i64,
+    #[skip]
+    #[serde(skip)]
+    asset_id: i64,
+    /// The new file name of the asset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl EditReleaseAssetBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, release_id: i64, asset_id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            release_id,
+            asset_id,
+            name: None,
+        }
+    }
+    /// Send the request to edit the asset.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Attachment>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (id, asset) = (self.release_id, self.asset_id);
+            let req = client
+                .patch(format!("repos/{owner}/{repo}/releases/{id}/assets/{asset}"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteReleaseAssetBuilder {
+    owner: String,
+    repo: String,
+    release_id: i64,
+    asset_id: i64,
+}
+
+impl DeleteReleaseAssetBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, release_id: i64, asset_id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            release_id,
+            asset_id,
+        }
+    }
+    /// Send the request to delete the asset.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let (id, asset) = (self.release_id, self.asset_id);
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/releases/{id}/assets/{asset}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+/// Uploads a file as an asset of a release.
+/// The file's contents can be anything that converts into a [reqwest::Body], like a `Vec<u8>`
+/// or a [tokio::fs::File], which is streamed instead of being read into memory first.
+pub struct UploadReleaseAssetBuilder {
+    owner: String,
+    repo: String,
+    release_id: i64,
+    name: String,
+    content_type: Option<String>,
+    body: Body,
+}
+
+impl UploadReleaseAssetBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        release_id: i64,
+        name: impl ToString,
+        body: impl Into<Body>,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            release_id,
+            name: name.to_string(),
+            content_type: None,
+            body: body.into(),
+        }
+    }
+    /// The MIME type of the file, like `application/gzip`.
+    /// Defaults to `application/octet-stream`.
+    pub fn content_type(mut self, content_type: impl ToString) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+    /// Send the request to upload the asset.
+    /// NOTE: Uploads with a streaming body are never retried.
+    pub fn send<'a, E: Executor>(self, executor: &'a E) -> E::Output<'a, Result<Attachment>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.release_id;
+            let content_type = self
+                .content_type
+                .as_deref()
+                .unwrap_or("application/octet-stream");
+            let part = Part::stream(self.body)
+                .file_name(self.name.clone())
+                .mime_str(content_type)?;
+            let req = client
+                .post(format!("repos/{owner}/{repo}/releases/{id}/assets"))
+                .query(&[("name", &self.name)])
+                .multipart(Form::new().part("attachment", part))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DownloadReleaseAssetBuilder {
+    owner: String,
+    repo: String,
+    release_id: i64,
+    asset_id: i64,
+}
+
+impl DownloadReleaseAssetBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, release_id: i64, asset_id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            release_id,
+            asset_id,
+        }
+    }
+    /// Send the request to download the asset.
+    /// This looks up the asset's download URL and starts the download. The returned
+    /// [AssetDownload] is used to read the asset's contents.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<AssetDownload>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let asset = GetReleaseAssetBuilder::new(
+                &self.owner,
+                &self.repo,
+                self.release_id,
+                self.asset_id,
+            )
+            .send(client)
+            .await?;
+            let response = client.get_url(&asset.browser_download_url).await?;
+            Ok(AssetDownload { asset, response })
+        })
+    }
+}
+
+/// A release asset that is being downloaded.
+///
+/// The asset's contents are not read until you ask for them, so large files can be streamed
+/// (for example into a file) with [AssetDownload::chunk] or [AssetDownload::bytes_stream].
+/// With a blocking client, use its `block_on` method to read them.
+#[derive(Debug)]
+pub struct AssetDownload {
+    /// The asset's metadata.
+    pub asset: Attachment,
+    response: Response,
+}
+
+impl AssetDownload {
+    /// The size of the asset in bytes, if the server sent it.
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+    /// Reads the next chunk of the asset's contents.
+    /// Returns `None` once the whole asset has been read.
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>> {
+        Ok(self.response.chunk().await?)
+    }
+    /// Returns a stream of the asset's contents.
+    pub fn bytes_stream(self) -> impl Stream<Item = Result<Bytes>> {
+        self.response.bytes_stream().map(|chunk| Ok(chunk?))
+    }
+    /// Reads the whole asset into memory.
+    pub async fn bytes(self) -> Result<Bytes> {
+        Ok(self.response.bytes().await?)
+    }
+}
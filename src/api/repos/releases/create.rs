@@ -0,0 +1,65 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::releases::Release, path::segment, Executor};
+
+/// Options for creating a release.
+/// The only required field is the tag name.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateReleaseBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    /// The name of the tag to create the release for.
+    #[skip]
+    tag_name: String,
+    /// The branch or commit to create the tag from, if the tag doesn't exist yet.
+    /// Defaults to the repository's default branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_commitish: Option<String>,
+    /// The title of the release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// The release notes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    /// Whether to create the release as a draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    draft: Option<bool>,
+    /// Whether to mark the release as a pre-release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prerelease: Option<bool>,
+}
+
+impl CreateReleaseBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, tag_name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            tag_name: tag_name.to_string(),
+            target_commitish: None,
+            name: None,
+            body: None,
+            draft: None,
+            prerelease: None,
+        }
+    }
+    /// Send the request to create the release.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Release>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/releases"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
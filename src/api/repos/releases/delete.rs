@@ -0,0 +1,66 @@
+use crate::error::Result;
+use crate::path::segment;
+use crate::Executor;
+
+#[derive(Debug, Clone)]
+pub struct DeleteReleaseBuilder {
+    owner: String,
+    repo: String,
+    id: i64,
+}
+
+impl DeleteReleaseBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+        }
+    }
+    /// Send the request to delete the release.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/releases/{id}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteReleaseByTagBuilder {
+    owner: String,
+    repo: String,
+    tag: String,
+}
+
+impl DeleteReleaseByTagBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, tag: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            tag: tag.to_string(),
+        }
+    }
+    /// Send the request to delete the release.
+    /// NOTE: This only deletes the release, the tag itself is kept.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let tag = segment(&self.tag);
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/releases/tags/{tag}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
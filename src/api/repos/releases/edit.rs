@@ -0,0 +1,69 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::releases::Release, path::segment, Executor};
+
+/// Options for editing a release.
+/// Fields that are not set are left unchanged.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditReleaseBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// The name of the tag the release is attached to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag_name: Option<String>,
+    /// The branch or commit to create the tag from, if the tag doesn't exist yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_commitish: Option<String>,
+    /// The title of the release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// The release notes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    /// Whether the release is a draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    draft: Option<bool>,
+    /// Whether the release is a pre-release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prerelease: Option<bool>,
+}
+
+impl EditReleaseBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            tag_name: None,
+            target_commitish: None,
+            name: None,
+            body: None,
+            draft: None,
+            prerelease: None,
+        }
+    }
+    /// Send the request to edit the release.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Release>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .patch(format!("repos/{owner}/{repo}/releases/{id}"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
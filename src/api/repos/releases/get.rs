@@ -0,0 +1,94 @@
+use crate::error::Result;
+use crate::model::releases::Release;
+use crate::path::segment;
+use crate::Executor;
+
+#[derive(Debug, Clone)]
+pub struct GetReleaseBuilder {
+    owner: String,
+    repo: String,
+    id: i64,
+}
+
+impl GetReleaseBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+        }
+    }
+    /// Send the request to get the release.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Release>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .get(format!("repos/{owner}/{repo}/releases/{id}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetLatestReleaseBuilder {
+    owner: String,
+    repo: String,
+}
+
+impl GetLatestReleaseBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+    /// Send the request to get the latest release.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Release>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/releases/latest"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetReleaseByTagBuilder {
+    owner: String,
+    repo: String,
+    tag: String,
+}
+
+impl GetReleaseByTagBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, tag: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            tag: tag.to_string(),
+        }
+    }
+    /// Send the request to get the release.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Release>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let tag = segment(&self.tag);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/releases/tags/{tag}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
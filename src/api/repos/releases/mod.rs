@@ -0,0 +1,366 @@
+pub mod assets;
+pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod list;
+
+/// The [Releases] struct provides methods for managing a repository's releases and their
+/// assets.
+pub struct Releases {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl Releases {
+    /// Lists a repository's releases, newest first.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_releases() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let releases = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> list::ListReleasesBuilder {
+        list::ListReleasesBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Creates a release for a tag.
+    /// If the tag doesn't exist yet, it is created from
+    /// [target_commitish](create::CreateReleaseBuilder::target_commitish) (the default branch if not set).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_release() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let release = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .create("v1.0.0")
+    ///     .name("Version 1.0.0")
+    ///     .body("Initial release")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn create(&self, tag_name: impl ToString) -> create::CreateReleaseBuilder {
+        create::CreateReleaseBuilder::new(&self.owner, &self.repo, tag_name)
+    }
+
+    /// Gets a release by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_release() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let release = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, id: i64) -> get::GetReleaseBuilder {
+        get::GetReleaseBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Gets the latest release.
+    /// Drafts and pre-releases are never returned as the latest release.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_latest_release() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let release = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .latest()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn latest(&self) -> get::GetLatestReleaseBuilder {
+        get::GetLatestReleaseBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Gets the release for a tag.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_release_by_tag() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let release = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .get_by_tag("v1.0.0")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get_by_tag(&self, tag: impl ToString) -> get::GetReleaseByTagBuilder {
+        get::GetReleaseByTagBuilder::new(&self.owner, &self.repo, tag)
+    }
+
+    /// Edits a release.
+    /// Fields that are not set are left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_release() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let release = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .edit(1)
+    ///     .draft(false)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self, id: i64) -> edit::EditReleaseBuilder {
+        edit::EditReleaseBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Deletes a release and its assets.
+    /// The release's tag is kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_release() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .delete(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, id: i64) -> delete::DeleteReleaseBuilder {
+        delete::DeleteReleaseBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Deletes the release for a tag and its assets.
+    /// The tag itself is kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_release_by_tag() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .delete_by_tag("v1.0.0")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete_by_tag(&self, tag: impl ToString) -> delete::DeleteReleaseByTagBuilder {
+        delete::DeleteReleaseByTagBuilder::new(&self.owner, &self.repo, tag)
+    }
+
+    /// Lists the files attached to a release.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_assets() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let assets = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .list_assets(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_assets(&self, release_id: i64) -> assets::ListReleaseAssetsBuilder {
+        assets::ListReleaseAssetsBuilder::new(&self.owner, &self.repo, release_id)
+    }
+
+    /// Gets the metadata of a file attached to a release.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_asset() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let asset = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .get_asset(1, 2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get_asset(&self, release_id: i64, asset_id: i64) -> assets::GetReleaseAssetBuilder {
+        assets::GetReleaseAssetBuilder::new(&self.owner, &self.repo, release_id, asset_id)
+    }
+
+    /// Uploads a file and attaches it to a release.
+    /// The contents can be anything that converts into a [reqwest::Body], like a `Vec<u8>` or a
+    /// [tokio::fs::File]. Files are streamed instead of being read into memory first.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn upload_asset() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let contents = tokio::fs::File::open("target/app.tar.gz").await.unwrap();
+    /// let asset = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .upload_asset(1, "app.tar.gz", contents)
+    ///     .content_type("application/gzip")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn upload_asset(
+        &self,
+        release_id: i64,
+        name: impl ToString,
+        contents: impl Into<reqwest::Body>,
+    ) -> assets::UploadReleaseAssetBuilder {
+        assets::UploadReleaseAssetBuilder::new(&self.owner, &self.repo, release_id, name, contents)
+    }
+
+    /// Edits a file attached to a release.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_asset() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let asset = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .edit_asset(1, 2)
+    ///     .name("app-linux.tar.gz")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit_asset(&self, release_id: i64, asset_id: i64) -> assets::EditReleaseAssetBuilder {
+        assets::EditReleaseAssetBuilder::new(&self.owner, &self.repo, release_id, asset_id)
+    }
+
+    /// Deletes a file attached to a release.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_asset() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .delete_asset(1, 2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete_asset(
+        &self,
+        release_id: i64,
+        asset_id: i64,
+    ) -> assets::DeleteReleaseAssetBuilder {
+        assets::DeleteReleaseAssetBuilder::new(&self.owner, &self.repo, release_id, asset_id)
+    }
+
+    /// Downloads a file attached to a release.
+    /// The contents are streamed, see [AssetDownload](assets::AssetDownload).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn download_asset() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let download = client
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .download_asset(1, 2)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// let contents = download.bytes().await.unwrap();
+    /// # }
+    /// ```
+    pub fn download_asset(
+        &self,
+        release_id: i64,
+        asset_id: i64,
+    ) -> assets::DownloadReleaseAssetBuilder {
+        assets::DownloadReleaseAssetBuilder::new(&self.owner, &self.repo, release_id, asset_id)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{error::Result, model::statuses::CombinedStatus, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct GetCombinedStatusBuilder {
+    owner: String,
+    repo: String,
+    r#ref: String,
+}
+
+impl GetCombinedStatusBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, r#ref: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            r#ref: r#ref.to_string(),
+        }
+    }
+    /// Send the request to get the combined status.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<CombinedStatus>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let reference = segment(&self.r#ref);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/commits/{reference}/status"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
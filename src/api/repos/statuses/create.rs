@@ -0,0 +1,71 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::statuses::{CommitStatus, CommitStatusState},
+    path::segment,
+    Executor,
+};
+
+/// Options for creating a commit status.
+/// Only the state is required.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateStatusBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    sha: String,
+    #[skip]
+    state: CommitStatusState,
+    /// The URL to the details of the status, like a build log.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_url: Option<String>,
+    /// A short description of the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// The label that distinguishes this status from those of other services, like
+    /// `ci/build`. Defaults to `default`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
+}
+
+impl CreateStatusBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        sha: impl ToString,
+        state: CommitStatusState,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            sha: sha.to_string(),
+            state,
+            target_url: None,
+            description: None,
+            context: None,
+        }
+    }
+    /// Send the request to create the status.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<CommitStatus>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let sha = segment(&self.sha);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/statuses/{sha}"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::statuses::{CommitStatus, CommitStatusState},
+    pagination::Paginated,
+    path::segment,
+    Executor,
+};
+
+/// The order commit statuses are listed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusSort {
+    Oldest,
+    RecentUpdate,
+    LeastUpdate,
+    LeastIndex,
+    HighestIndex,
+}
+
+/// Options for listing the statuses of a commit.
+/// All fields are optional.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListStatusesBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    r#ref: String,
+    /// The order to list the statuses in. Defaults to the newest first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<StatusSort>,
+    /// Only return statuses in the given state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<CommitStatusState>,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListStatusesBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, r#ref: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            r#ref: r#ref.to_string(),
+            sort: None,
+            state: None,
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the statuses.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<CommitStatus>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListStatusesBuilder {
+    type Item = CommitStatus;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        let reference = segment(&self.r#ref);
+        format!("repos/{owner}/{repo}/commits/{reference}/statuses")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::model::statuses::CommitStatusState;
+
+pub mod combined;
+pub mod create;
+pub mod list;
+
+/// The [Statuses] struct provides methods for reporting and reading the statuses of commits,
+/// like the results of CI builds.
+pub struct Statuses {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl Statuses {
+    /// Creates a status for a commit.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::statuses::CommitStatusState};
+    /// # async fn create_status() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let status = client
+    ///     .repos("owner", "repo")
+    ///     .statuses()
+    ///     .create("e5d4c3b2a1", CommitStatusState::Success)
+    ///     .context("ci/build")
+    ///     .description("Build succeeded in 3m 12s")
+    ///     .target_url("https://ci.example.com/builds/42")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will mark the `ci/build` check of commit `e5d4c3b2a1` as successful.
+    pub fn create(
+        &self,
+        sha: impl ToString,
+        state: CommitStatusState,
+    ) -> create::CreateStatusBuilder {
+        create::CreateStatusBuilder::new(&self.owner, &self.repo, sha, state)
+    }
+
+    /// Lists the statuses of a commit, given as a SHA, branch or tag name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_statuses() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let statuses = client
+    ///     .repos("owner", "repo")
+    ///     .statuses()
+    ///     .list("main")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self, r#ref: impl ToString) -> list::ListStatusesBuilder {
+        list::ListStatusesBuilder::new(&self.owner, &self.repo, r#ref)
+    }
+
+    /// Gets the combined status of a commit, given as a SHA, branch or tag name.
+    /// The combined status contains the latest status of each context and an overall state.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, model::statuses::CommitStatusState};
+    /// # async fn combined_status() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let combined = client
+    ///     .repos("owner", "repo")
+    ///     .statuses()
+    ///     .combined("main")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// let passing = combined.state == CommitStatusState::Success;
+    /// # }
+    /// ```
+    pub fn combined(&self, r#ref: impl ToString) -> combined::GetCombinedStatusBuilder {
+        combined::GetCombinedStatusBuilder::new(&self.owner, &self.repo, r#ref)
+    }
+}
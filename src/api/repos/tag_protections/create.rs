@@ -0,0 +1,56 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::repos::TagProtection, path::segment, Executor};
+
+/// Options for creating a tag protection.
+/// The only required field is the name pattern.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateTagProtectionBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    /// The tag names to protect, as a glob (like `v*`) or a regular expression enclosed in
+    /// slashes (like `/^v\d+$/`).
+    #[skip]
+    name_pattern: String,
+    /// The usernames of the users that may create and delete matching tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    whitelist_usernames: Option<Vec<String>>,
+    /// The names of the teams that may create and delete matching tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    whitelist_teams: Option<Vec<String>>,
+}
+
+impl CreateTagProtectionBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, name_pattern: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            name_pattern: name_pattern.to_string(),
+            whitelist_usernames: None,
+            whitelist_teams: None,
+        }
+    }
+    /// Send the request to create the tag protection.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<TagProtection>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/tag_protections"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
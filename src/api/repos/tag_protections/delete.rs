@@ -0,0 +1,32 @@
+use crate::{error::Result, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct DeleteTagProtectionBuilder {
+    owner: String,
+    repo: String,
+    id: i64,
+}
+
+impl DeleteTagProtectionBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+        }
+    }
+    /// Send the request to delete the tag protection.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/tag_protections/{id}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
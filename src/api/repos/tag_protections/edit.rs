@@ -0,0 +1,63 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::repos::TagProtection, path::segment, Executor};
+
+/// Options for editing a tag protection.
+/// Fields that are not set are left unchanged.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditTagProtectionBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// The tag names to protect, as a glob (like `v*`) or a regular expression enclosed in
+    /// slashes (like `/^v\d+$/`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_pattern: Option<String>,
+    /// The usernames of the users that may create and delete matching tags.
+    /// Replaces the current list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    whitelist_usernames: Option<Vec<String>>,
+    /// The names of the teams that may create and delete matching tags.
+    /// Replaces the current list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    whitelist_teams: Option<Vec<String>>,
+}
+
+impl EditTagProtectionBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, id: i64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            id,
+            name_pattern: None,
+            whitelist_usernames: None,
+            whitelist_teams: None,
+        }
+    }
+    /// Send the request to edit the tag protection.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<TagProtection>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let id = self.id;
+            let req = client
+                .patch(format!("repos/{owner}/{repo}/tag_protections/{id}"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
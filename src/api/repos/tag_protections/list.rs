@@ -0,0 +1,32 @@
+use crate::{error::Result, model::repos::TagProtection, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct ListTagProtectionsBuilder {
+    owner: String,
+    repo: String,
+}
+
+impl ListTagProtectionsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+    /// Send the request to list the tag protections.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<TagProtection>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/tag_protections"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
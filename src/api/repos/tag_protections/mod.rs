@@ -0,0 +1,134 @@
+pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod list;
+
+/// The [TagProtections] struct provides methods for managing a repository's tag protection
+/// rules.
+pub struct TagProtections {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl TagProtections {
+    /// Lists the repository's [tag protections](crate::model::repos::TagProtection).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_tag_protections() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let protections = client
+    ///     .repos("owner", "repo")
+    ///     .tag_protections()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> list::ListTagProtectionsBuilder {
+        list::ListTagProtectionsBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Gets a tag protection by its ID.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_tag_protection() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let protection = client
+    ///     .repos("owner", "repo")
+    ///     .tag_protections()
+    ///     .get(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, id: i64) -> get::GetTagProtectionBuilder {
+        get::GetTagProtectionBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Creates a tag protection for the tags matching a pattern.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_tag_protection() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let protection = client
+    ///     .repos("owner", "repo")
+    ///     .tag_protections()
+    ///     .create("v*")
+    ///     .whitelist_usernames(vec!["release-bot".to_string()])
+    ///     .whitelist_teams(vec!["maintainers".to_string()])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will only allow `release-bot` and the `maintainers` team to create and delete tags
+    /// starting with `v`.
+    pub fn create(&self, name_pattern: impl ToString) -> create::CreateTagProtectionBuilder {
+        create::CreateTagProtectionBuilder::new(&self.owner, &self.repo, name_pattern)
+    }
+
+    /// Edits a tag protection.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_tag_protection() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let protection = client
+    ///     .repos("owner", "repo")
+    ///     .tag_protections()
+    ///     .edit(1)
+    ///     .name_pattern("release-*")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self, id: i64) -> edit::EditTagProtectionBuilder {
+        edit::EditTagProtectionBuilder::new(&self.owner, &self.repo, id)
+    }
+
+    /// Deletes a tag protection.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_tag_protection() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .tag_protections()
+    ///     .delete(1)
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, id: i64) -> delete::DeleteTagProtectionBuilder {
+        delete::DeleteTagProtectionBuilder::new(&self.owner, &self.repo, id)
+    }
+}
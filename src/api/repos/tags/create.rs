@@ -0,0 +1,53 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::repos::Tag, path::segment, Executor};
+
+/// Options for creating a tag.
+/// The only required field is the name. Without a [message](Self::message), a lightweight tag
+/// is created.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct CreateTagBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    /// The name of the tag.
+    #[skip]
+    tag_name: String,
+    /// The message of the tag. Setting a message creates an annotated tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    /// The commit SHA or branch name to tag. Defaults to the default branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+}
+
+impl CreateTagBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, tag_name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            tag_name: tag_name.to_string(),
+            message: None,
+            target: None,
+        }
+    }
+    /// Send the request to create the tag.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Tag>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .post(format!("repos/{owner}/{repo}/tags"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
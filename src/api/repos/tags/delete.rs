@@ -0,0 +1,37 @@
+use crate::{
+    error::Result,
+    path::{segment, segments},
+    Executor,
+};
+
+#[derive(Debug, Clone)]
+pub struct DeleteTagBuilder {
+    owner: String,
+    repo: String,
+    name: String,
+}
+
+impl DeleteTagBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            name: name.to_string(),
+        }
+    }
+    /// Send the request to delete the tag.
+    /// Tags that are used by a release can't be deleted. Delete the release first.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let name = segments(&self.name);
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/tags/{name}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    error::Result,
+    model::repos::Tag,
+    path::{segment, segments},
+    Executor,
+};
+
+#[derive(Debug, Clone)]
+pub struct GetTagBuilder {
+    owner: String,
+    repo: String,
+    name: String,
+}
+
+impl GetTagBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, name: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            name: name.to_string(),
+        }
+    }
+    /// Send the request to get the tag.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Tag>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let name = segments(&self.name);
+            let req = client
+                .get(format!("repos/{owner}/{repo}/tags/{name}"))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,111 @@
+pub mod create;
+pub mod delete;
+pub mod get;
+pub mod list;
+
+/// The [Tags] struct provides methods for managing a repository's tags.
+/// See [Repos::tag_protections](crate::api::repos::Repos::tag_protections) to restrict who may
+/// create and delete them.
+pub struct Tags {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl Tags {
+    /// Lists the repository's [tags](crate::model::repos::Tag).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_tags() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let tags = client
+    ///     .repos("owner", "repo")
+    ///     .tags()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> list::ListTagsBuilder {
+        list::ListTagsBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Gets a tag by its name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_tag() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let tag = client
+    ///     .repos("owner", "repo")
+    ///     .tags()
+    ///     .get("v1.0.0")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self, name: impl ToString) -> get::GetTagBuilder {
+        get::GetTagBuilder::new(&self.owner, &self.repo, name)
+    }
+
+    /// Creates a tag.
+    /// Set a [message](create::CreateTagBuilder::message) to create an annotated tag, otherwise a
+    /// lightweight tag is created.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn create_tag() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let tag = client
+    ///     .repos("owner", "repo")
+    ///     .tags()
+    ///     .create("v1.0.0")
+    ///     .message("First stable release")
+    ///     .target("main")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    /// This will create an annotated tag `v1.0.0` at the head of `main`.
+    pub fn create(&self, name: impl ToString) -> create::CreateTagBuilder {
+        create::CreateTagBuilder::new(&self.owner, &self.repo, name)
+    }
+
+    /// Deletes a tag by its name.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_tag() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .tags()
+    ///     .delete("v1.0.0")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, name: impl ToString) -> delete::DeleteTagBuilder {
+        delete::DeleteTagBuilder::new(&self.owner, &self.repo, name)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{error::Result, model::team::Team, path::segment, Executor};
+
+#[derive(Debug, Clone)]
+pub struct ListRepoTeamsBuilder {
+    owner: String,
+    repo: String,
+}
+
+impl ListRepoTeamsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+    /// Send the request to list the teams with access to the repository.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Team>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client.get(format!("repos/{owner}/{repo}/teams")).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
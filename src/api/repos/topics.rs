@@ -0,0 +1,281 @@
+use build_it::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    error::Result,
+    pagination::{decode, Paginated},
+    path::segment,
+    Executor,
+};
+
+/// The [Topics] struct provides methods for managing the topics a repository is tagged with.
+pub struct Topics {
+    pub(crate) owner: String,
+    pub(crate) repo: String,
+}
+
+impl Topics {
+    /// Lists the repository's topics.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_topics() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let topics = client
+    ///     .repos("owner", "repo")
+    ///     .topics()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListTopicsBuilder {
+        ListTopicsBuilder::new(&self.owner, &self.repo)
+    }
+
+    /// Replaces all of the repository's topics.
+    /// An empty list removes all topics.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn replace_topics() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .topics()
+    ///     .replace(["rust", "sdk"])
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn replace(&self, topics: impl IntoIterator<Item = impl ToString>) -> ReplaceTopicsBuilder {
+        ReplaceTopicsBuilder::new(&self.owner, &self.repo, topics)
+    }
+
+    /// Adds a topic to the repository.
+    /// Topics are lowercase and may only contain letters, numbers, dashes and dots.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_topic() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .topics()
+    ///     .add("rust")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add(&self, topic: impl ToString) -> AddTopicBuilder {
+        AddTopicBuilder::new(&self.owner, &self.repo, topic)
+    }
+
+    /// Deletes a topic from the repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_topic() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .repos("owner", "repo")
+    ///     .topics()
+    ///     .delete("rust")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self, topic: impl ToString) -> DeleteTopicBuilder {
+        DeleteTopicBuilder::new(&self.owner, &self.repo, topic)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTopicsBuilder {
+    #[skip]
+    #[serde(skip)]
+    owner: String,
+    #[skip]
+    #[serde(skip)]
+    repo: String,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct TopicNames {
+    topics: Vec<String>,
+}
+
+impl ListTopicsBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the topics.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<String>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            Ok(client.parse_response::<TopicNames>(res).await?.topics)
+        })
+    }
+}
+
+impl Paginated for ListTopicsBuilder {
+    type Item = String;
+
+    fn path(&self) -> String {
+        let owner = segment(&self.owner);
+        let repo = segment(&self.repo);
+        format!("repos/{owner}/{repo}/topics")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+
+    fn items(body: Value) -> Result<Vec<Self::Item>> {
+        Ok(decode::<TopicNames>(body)?.topics)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplaceTopicsBuilder {
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    topics: Vec<String>,
+}
+
+impl ReplaceTopicsBuilder {
+    pub fn new(
+        owner: impl ToString,
+        repo: impl ToString,
+        topics: impl IntoIterator<Item = impl ToString>,
+    ) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            topics: topics.into_iter().map(|t| t.to_string()).collect(),
+        }
+    }
+    /// Send the request to replace the topics.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .put(format!("repos/{owner}/{repo}/topics"))
+                .json(self)
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+/// Adds a topic to a repository.
+#[derive(Debug, Clone)]
+pub struct AddTopicBuilder {
+    owner: String,
+    repo: String,
+    topic: String,
+}
+
+/// Deletes a topic from a repository.
+#[derive(Debug, Clone)]
+pub struct DeleteTopicBuilder {
+    owner: String,
+    repo: String,
+    topic: String,
+}
+
+impl AddTopicBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, topic: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            topic: topic.to_string(),
+        }
+    }
+    /// Send the request to add the topic.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let topic = segment(&self.topic);
+            let req = client
+                .put(format!("repos/{owner}/{repo}/topics/{topic}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+impl DeleteTopicBuilder {
+    pub fn new(owner: impl ToString, repo: impl ToString, topic: impl ToString) -> Self {
+        Self {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            topic: topic.to_string(),
+        }
+    }
+    /// Send the request to delete the topic.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let topic = segment(&self.topic);
+            let req = client
+                .delete(format!("repos/{owner}/{repo}/topics/{topic}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
@@ -3,6 +3,9 @@ use serde::Serialize;
 
 use crate::error::Result;
 use crate::model::issues::{Issue, IssueType, State};
+use crate::model::Timestamp;
+use crate::pagination::Paginated;
+use crate::Executor;
 
 /// Options for searching issues.
 /// All fields are optional.
@@ -25,12 +28,12 @@ pub struct SearchIssuesBuilder {
     /// Filter by type (issue or pull request) if set
     #[serde(rename = "type")]
     issue_type: Option<IssueType>,
-    /// Only show issues updated after the given time. This is a timestamp in RFC 3339 format.
-    // TODO: Make this a DateTime<Utc>
-    since: Option<String>,
-    /// Only show issues updated before the given time. This is a timestamp in RFC 3339 format.
-    // TODO: Make this a DateTime<Utc>
-    before: Option<String>,
+    /// Only show issues updated after the given time.
+    /// Accepts an RFC 3339 string, or a `chrono::DateTime` with the `chrono` feature.
+    since: Option<Timestamp>,
+    /// Only show issues updated before the given time.
+    /// Accepts an RFC 3339 string, or a `chrono::DateTime` with the `chrono` feature.
+    before: Option<Timestamp>,
     /// Filter issues/PRs assigned to the authenticated user, default is false
     assigned: Option<bool>,
     /// Filter issues/PRs created by the authenticated user, default is false
@@ -58,12 +61,32 @@ impl SearchIssuesBuilder {
     /// Send the request to search for issues.
     /// This will return a [Vec<Issue>] of all issues matching the search criteria.
     /// Only shows issues the currently authenticated user can see.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Issue>> {
-        let req = client
-            .get("repos/issues/search".to_string())
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Issue>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for SearchIssuesBuilder {
+    type Item = Issue;
+
+    fn path(&self) -> String {
+        "repos/issues/search".to_string()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page.map(i64::from)
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit.map(i64::from)
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page as i32),
+            ..self.clone()
+        }
     }
 }
@@ -1,5 +1,6 @@
 pub mod issues;
 pub mod repos;
+pub mod topics;
 pub mod users;
 
 pub struct Search;
@@ -81,4 +82,28 @@ impl Search {
     pub fn issues(&self) -> issues::SearchIssuesBuilder {
         issues::SearchIssuesBuilder::new()
     }
+
+    /// Searches for topics by name.
+    /// This method will return a list of topics that match the search criteria.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn search_topics() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let topics = client
+    ///     .search()
+    ///     .topics()
+    ///     .query("rust")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn topics(&self) -> topics::SearchTopicsBuilder {
+        topics::SearchTopicsBuilder::new()
+    }
 }
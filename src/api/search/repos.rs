@@ -1,8 +1,11 @@
 use build_it::Builder;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::error::Result;
 use crate::model::repos::Repository;
+use crate::pagination::{decode, Paginated};
+use crate::Executor;
 
 /// Options for searching repositories.
 /// All fields are optional.
@@ -52,15 +55,45 @@ impl SearchRepositoriesBuilder {
     pub fn new() -> Self {
         Self::default()
     }
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Repository>> {
-        let req = client.get("repos/search".to_string()).query(self).build()?;
-        #[derive(Deserialize)]
-        struct Response {
-            #[allow(dead_code)]
-            ok: bool,
-            data: Vec<Repository>,
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<Repository>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            #[derive(Deserialize)]
+            struct Response {
+                #[allow(dead_code)]
+                ok: bool,
+                data: Vec<Repository>,
+            }
+            let res = client.make_request(req).await?;
+            Ok(client.parse_response::<Response>(res).await?.data)
+        })
+    }
+}
+
+impl Paginated for SearchRepositoriesBuilder {
+    type Item = Repository;
+
+    fn path(&self) -> String {
+        "repos/search".to_string()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page.map(i64::from)
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit.map(i64::from)
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page as i32),
+            ..self.clone()
         }
-        let res = client.make_request(req).await?;
-        Ok(client.parse_response::<Response>(res).await?.data)
+    }
+
+    fn items(mut body: Value) -> Result<Vec<Self::Item>> {
+        decode(body["data"].take())
     }
 }
@@ -0,0 +1,67 @@
+use build_it::Builder;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::Result;
+use crate::model::repos::Topic;
+use crate::pagination::{decode, Paginated};
+use crate::Executor;
+
+/// Options for searching topics.
+/// All fields are optional.
+#[derive(Default, Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct SearchTopicsBuilder {
+    /// Keyword to search for
+    #[serde(rename = "q")]
+    query: Option<String>,
+    /// Page number of results to return (1-based)
+    page: Option<i32>,
+    /// Page size of results
+    limit: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    topics: Vec<Topic>,
+}
+
+impl SearchTopicsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Send the request to search the topics.
+    /// This will return a list of the [Topic]s matching the keyword.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Topic>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            Ok(client.parse_response::<Response>(res).await?.topics)
+        })
+    }
+}
+
+impl Paginated for SearchTopicsBuilder {
+    type Item = Topic;
+
+    fn path(&self) -> String {
+        "topics/search".to_string()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page.map(i64::from)
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit.map(i64::from)
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page as i32),
+            ..self.clone()
+        }
+    }
+
+    fn items(body: Value) -> Result<Vec<Self::Item>> {
+        Ok(decode::<Response>(body)?.topics)
+    }
+}
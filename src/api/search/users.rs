@@ -1,8 +1,11 @@
 use build_it::Builder;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::error::Result;
 use crate::model::user::User;
+use crate::pagination::{decode, Paginated};
+use crate::Executor;
 
 /// Options for searching users.
 /// All fields are optional.
@@ -27,15 +30,42 @@ impl SearchUsersBuilder {
     /// Send the request to get the repository.
     /// This will return a [Vec<User>] object if the repository exists and is visible to the
     /// currently authenticated user.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<User>> {
-        let req = client.get("users/search".to_string()).query(self).build()?;
-        #[derive(Deserialize)]
-        struct Response {
-            #[allow(dead_code)]
-            ok: bool,
-            data: Vec<User>,
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<User>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            #[derive(Deserialize)]
+            struct Response {
+                #[allow(dead_code)]
+                ok: bool,
+                data: Vec<User>,
+            }
+            let res = client.make_request(req).await?;
+            Ok(client.parse_response::<Response>(res).await?.data)
+        })
+    }
+}
+
+impl Paginated for SearchUsersBuilder {
+    type Item = User;
+
+    fn path(&self) -> String {
+        "users/search".to_string()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page.map(i64::from)
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit.map(i64::from)
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page as i32),
+            ..self.clone()
         }
-        let res = client.make_request(req).await?;
-        Ok(client.parse_response::<Response>(res).await?.data)
+    }
+
+    fn items(mut body: Value) -> Result<Vec<Self::Item>> {
+        decode(body["data"].take())
     }
 }
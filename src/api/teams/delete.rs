@@ -0,0 +1,21 @@
+use crate::{error::Result, Executor};
+
+#[derive(Debug, Clone)]
+pub struct DeleteTeamBuilder {
+    id: i64,
+}
+
+impl DeleteTeamBuilder {
+    pub fn new(id: i64) -> Self {
+        Self { id }
+    }
+    /// Send the request to delete the team.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.delete(format!("teams/{}", self.id)).build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
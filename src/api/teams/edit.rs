@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::Result,
+    model::team::{Permission, RepoUnit, Team},
+    Executor,
+};
+
+/// Options for editing a team.
+/// Fields that are not set are left unchanged.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct EditTeamBuilder {
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// The name of the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// The description of the team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// The team's permission for all units. Use [units_map](Self::units_map) for per-unit
+    /// permissions instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permission: Option<Permission>,
+    /// Whether the team has access to all repositories of the organization, including ones
+    /// created later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    includes_all_repositories: Option<bool>,
+    /// Whether members of the team may create repositories in the organization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_create_org_repo: Option<bool>,
+    /// The repository units the team has access to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units: Option<Vec<RepoUnit>>,
+    /// The team's permission for each repository unit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units_map: Option<HashMap<RepoUnit, Permission>>,
+}
+
+impl EditTeamBuilder {
+    pub fn new(id: i64) -> Self {
+        Self {
+            id,
+            name: None,
+            description: None,
+            permission: None,
+            includes_all_repositories: None,
+            can_create_org_repo: None,
+            units: None,
+            units_map: None,
+        }
+    }
+    /// Send the request to edit the team.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Team>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client
+                .patch(format!("teams/{}", self.id))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
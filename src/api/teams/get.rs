@@ -0,0 +1,21 @@
+use crate::{error::Result, model::team::Team, Executor};
+
+#[derive(Debug, Clone)]
+pub struct GetTeamBuilder {
+    id: i64,
+}
+
+impl GetTeamBuilder {
+    pub fn new(id: i64) -> Self {
+        Self { id }
+    }
+    /// Send the request to get the team.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Team>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(format!("teams/{}", self.id)).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
@@ -0,0 +1,126 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::{Result, TeatimeErrorKind},
+    model::user::User,
+    pagination::Paginated,
+    path::segment,
+    Executor,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTeamMembersBuilder {
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListTeamMembersBuilder {
+    pub fn new(id: i64) -> Self {
+        Self {
+            id,
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the team's members.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<User>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListTeamMembersBuilder {
+    type Item = User;
+
+    fn path(&self) -> String {
+        format!("teams/{}/members", self.id)
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IsTeamMemberBuilder {
+    id: i64,
+    username: String,
+}
+
+impl IsTeamMemberBuilder {
+    pub fn new(id: i64, username: impl ToString) -> Self {
+        Self {
+            id,
+            username: username.to_string(),
+        }
+    }
+    /// Send the request to check whether the user is a member of the team.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<bool>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let username = segment(&self.username);
+            let req = client
+                .get(format!("teams/{}/members/{username}", self.id))
+                .build()?;
+            match client.make_request(req).await {
+                Ok(_) => Ok(true),
+                Err(e) if e.kind == TeatimeErrorKind::NotFound => Ok(false),
+                Err(e) => Err(e),
+            }
+        })
+    }
+}
+
+/// Adds a user to a team, or removes them from it.
+#[derive(Debug, Clone)]
+pub struct SetTeamMemberBuilder {
+    id: i64,
+    username: String,
+    remove: bool,
+}
+
+impl SetTeamMemberBuilder {
+    pub fn new(id: i64, username: impl ToString, remove: bool) -> Self {
+        Self {
+            id,
+            username: username.to_string(),
+            remove,
+        }
+    }
+    /// Send the request to add or remove the member.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let username = segment(&self.username);
+            let path = format!("teams/{}/members/{username}", self.id);
+            let req = if self.remove {
+                client.delete(path)
+            } else {
+                client.put(path)
+            };
+            client.make_request(req.build()?).await?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,260 @@
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod members;
+pub mod repos;
+
+/// Methods for a single team, identified by its ID.
+/// Teams are listed and created through [Orgs](crate::api::orgs::Orgs).
+pub struct Teams {
+    pub(crate) id: i64,
+}
+
+impl Teams {
+    /// Gets a [Team](crate::model::team::Team).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn get_team() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let team = client
+    ///     .teams(1)
+    ///     .get()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn get(&self) -> get::GetTeamBuilder {
+        get::GetTeamBuilder::new(self.id)
+    }
+
+    /// Edits a [Team](crate::model::team::Team).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn edit_team() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let team = client
+    ///     .teams(1)
+    ///     .edit()
+    ///     .description("The core team")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn edit(&self) -> edit::EditTeamBuilder {
+        edit::EditTeamBuilder::new(self.id)
+    }
+
+    /// Deletes a [Team](crate::model::team::Team).
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn delete_team() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .teams(1)
+    ///     .delete()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn delete(&self) -> delete::DeleteTeamBuilder {
+        delete::DeleteTeamBuilder::new(self.id)
+    }
+
+    /// Lists the members of the team.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_team_members() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let members = client
+    ///     .teams(1)
+    ///     .list_members()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_members(&self) -> members::ListTeamMembersBuilder {
+        members::ListTeamMembersBuilder::new(self.id)
+    }
+
+    /// Checks whether a user is a member of the team.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn is_team_member() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let is_member = client
+    ///     .teams(1)
+    ///     .is_member("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn is_member(&self, username: impl ToString) -> members::IsTeamMemberBuilder {
+        members::IsTeamMemberBuilder::new(self.id, username)
+    }
+
+    /// Adds a user to the team.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_team_member() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .teams(1)
+    ///     .add_member("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add_member(&self, username: impl ToString) -> members::SetTeamMemberBuilder {
+        members::SetTeamMemberBuilder::new(self.id, username, false)
+    }
+
+    /// Removes a user from the team.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_team_member() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .teams(1)
+    ///     .remove_member("username")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn remove_member(&self, username: impl ToString) -> members::SetTeamMemberBuilder {
+        members::SetTeamMemberBuilder::new(self.id, username, true)
+    }
+
+    /// Lists the repositories the team has access to.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_team_repos() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let repos = client
+    ///     .teams(1)
+    ///     .list_repos()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_repos(&self) -> repos::ListTeamReposBuilder {
+        repos::ListTeamReposBuilder::new(self.id)
+    }
+
+    /// Checks whether the team has access to a repository of its organization.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn has_team_repo() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let has_repo = client
+    ///     .teams(1)
+    ///     .has_repo("org-name", "repo")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn has_repo(&self, org: impl ToString, repo: impl ToString) -> repos::HasTeamRepoBuilder {
+        repos::HasTeamRepoBuilder::new(self.id, org, repo)
+    }
+
+    /// Gives the team access to a repository of its organization.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn add_team_repo() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .teams(1)
+    ///     .add_repo("org-name", "repo")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add_repo(&self, org: impl ToString, repo: impl ToString) -> repos::SetTeamRepoBuilder {
+        repos::SetTeamRepoBuilder::new(self.id, org, repo, false)
+    }
+
+    /// Revokes the team's access to a repository.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn remove_team_repo() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// client
+    ///     .teams(1)
+    ///     .remove_repo("org-name", "repo")
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn remove_repo(
+        &self,
+        org: impl ToString,
+        repo: impl ToString,
+    ) -> repos::SetTeamRepoBuilder {
+        repos::SetTeamRepoBuilder::new(self.id, org, repo, true)
+    }
+}
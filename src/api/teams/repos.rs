@@ -0,0 +1,135 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{
+    error::{Result, TeatimeErrorKind},
+    model::repos::Repository,
+    pagination::Paginated,
+    path::segment,
+    Executor,
+};
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTeamReposBuilder {
+    #[skip]
+    #[serde(skip)]
+    id: i64,
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListTeamReposBuilder {
+    pub fn new(id: i64) -> Self {
+        Self {
+            id,
+            page: None,
+            limit: None,
+        }
+    }
+    /// Send the request to list the team's repositories.
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<Repository>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListTeamReposBuilder {
+    type Item = Repository;
+
+    fn path(&self) -> String {
+        format!("teams/{}/repos", self.id)
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HasTeamRepoBuilder {
+    id: i64,
+    org: String,
+    repo: String,
+}
+
+impl HasTeamRepoBuilder {
+    pub fn new(id: i64, org: impl ToString, repo: impl ToString) -> Self {
+        Self {
+            id,
+            org: org.to_string(),
+            repo: repo.to_string(),
+        }
+    }
+    /// Send the request to check whether the team has access to the repository.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<bool>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let org = segment(&self.org);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("teams/{}/repos/{org}/{repo}", self.id))
+                .build()?;
+            match client.make_request(req).await {
+                Ok(_) => Ok(true),
+                Err(e) if e.kind == TeatimeErrorKind::NotFound => Ok(false),
+                Err(e) => Err(e),
+            }
+        })
+    }
+}
+
+/// Gives a team access to a repository, or revokes it.
+#[derive(Debug, Clone)]
+pub struct SetTeamRepoBuilder {
+    id: i64,
+    org: String,
+    repo: String,
+    remove: bool,
+}
+
+impl SetTeamRepoBuilder {
+    pub fn new(id: i64, org: impl ToString, repo: impl ToString, remove: bool) -> Self {
+        Self {
+            id,
+            org: org.to_string(),
+            repo: repo.to_string(),
+            remove,
+        }
+    }
+    /// Send the request to add or remove the repository.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let org = segment(&self.org);
+            let repo = segment(&self.repo);
+            let path = format!("teams/{}/repos/{org}/{repo}", self.id);
+            let req = if self.remove {
+                client.delete(path)
+            } else {
+                client.put(path)
+            };
+            client.make_request(req.build()?).await?;
+            Ok(())
+        })
+    }
+}
@@ -3,7 +3,7 @@ use serde::Serialize;
 
 use crate::{
     model::repos::{ObjectFormatName, Repository, TrustModel},
-    Result,
+    Executor, Result,
 };
 
 /// Represents the options for creating a new repository.
@@ -63,9 +63,12 @@ impl CreateRepoBuilder {
     }
     /// Send the request to create the repository.
     /// This will return the created repository object.
-    pub async fn send(&self, client: &crate::Client) -> Result<Repository> {
-        let req = client.post("user/repos").json(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Repository>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.post("user/repos").json(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
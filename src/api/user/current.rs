@@ -1,5 +1,6 @@
 use crate::error::Result;
 use crate::model::user::User;
+use crate::Executor;
 
 #[derive(Default, Debug)]
 pub struct GetAuthenticatedUserBuilder;
@@ -10,10 +11,13 @@ impl GetAuthenticatedUserBuilder {
     }
 
     /// Send the request to get the authenticated user.
-    pub async fn send(&self, client: &crate::Client) -> Result<User> {
-        // send the request
-        let req = client.get("user").build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<User>> {
+        let client = executor.client();
+        executor.execute(async move {
+            // send the request
+            let req = client.get("user").build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
@@ -3,6 +3,8 @@ use serde::Serialize;
 
 use crate::error::Result;
 use crate::model::repos::Repository;
+use crate::pagination::Paginated;
+use crate::Executor;
 
 #[derive(Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
@@ -22,10 +24,16 @@ impl ListReposBuilder {
     }
 
     /// Send the request to list repositories.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<Repository>> {
-        let req = client.get("user/repos").query(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<Repository>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -34,3 +42,23 @@ impl Default for ListReposBuilder {
         Self::new()
     }
 }
+
+impl Paginated for ListReposBuilder {
+    type Item = Repository;
+
+    fn path(&self) -> String {
+        "user/repos".to_string()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod list_repos;
 pub mod orgs;
 pub mod settings;
 pub mod starred;
+pub mod teams;
 pub mod tokens;
 
 pub struct User;
@@ -356,4 +357,52 @@ impl User {
     ) -> starred::UnstarRepoBuilder {
         starred::UnstarRepoBuilder::new(owner, repo)
     }
+
+    /// Methods for managing the authenticated user's webhooks.
+    /// User webhooks are delivered for events in all of the user's repositories.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_user_hooks() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let hooks = client
+    ///     .user()
+    ///     .hooks()
+    ///     .list()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn hooks(&self) -> crate::api::hooks::Hooks {
+        crate::api::hooks::Hooks {
+            path: "user/hooks".to_string(),
+        }
+    }
+
+    /// Lists the teams the authenticated user is a member of, across all organizations.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth};
+    /// # async fn list_user_teams() {
+    /// let client = Client::new(
+    ///     "https://gitea.example.com",
+    ///     Auth::Token("your-token")
+    /// );
+    /// let teams = client
+    ///     .user()
+    ///     .list_teams()
+    ///     .send(&client)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn list_teams(&self) -> teams::ListTeamsBuilder {
+        teams::ListTeamsBuilder::new()
+    }
 }
@@ -1,9 +1,9 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{model::orgs::Organization, Client};
+use crate::{model::orgs::Organization, pagination::Paginated, Executor};
 
-#[derive(Debug, Default, Builder, Serialize)]
+#[derive(Debug, Default, Clone, Builder, Serialize)]
 pub struct Orgs {
     page: Option<i64>,
     limit: Option<i64>,
@@ -14,9 +14,35 @@ impl Orgs {
         Self::default()
     }
     /// Send the request to get the current user's organizations.
-    pub async fn send(&self, client: &Client) -> crate::Result<Vec<Organization>> {
-        let req = client.get("user/orgs").query(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, crate::Result<Vec<Organization>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for Orgs {
+    type Item = Organization;
+
+    fn path(&self) -> String {
+        "user/orgs".to_string()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
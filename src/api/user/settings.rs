@@ -1,7 +1,7 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::user::UserSettings, Client};
+use crate::{error::Result, model::user::UserSettings, Executor};
 
 #[derive(Default, Debug)]
 pub struct GetSettingsBuilder;
@@ -39,10 +39,13 @@ impl GetSettingsBuilder {
     }
 
     /// Sends the request to get the user's settings.
-    pub async fn send(self, client: &Client) -> Result<UserSettings> {
-        let req = client.get("user/settings").build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(self, executor: &'a E) -> E::Output<'a, Result<UserSettings>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get("user/settings").build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -52,9 +55,12 @@ impl UpdateSettingsBuilder {
     }
 
     /// Sends the request to update the user's settings.
-    pub async fn send(self, client: &Client) -> Result<UserSettings> {
-        let req = client.patch("user/settings").json(&self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(self, executor: &'a E) -> E::Output<'a, Result<UserSettings>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.patch("user/settings").json(&self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
@@ -1,8 +1,13 @@
 use build_it::Builder;
-use reqwest::StatusCode;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Repository, Client};
+use crate::{
+    error::{Result, TeatimeErrorKind},
+    model::repos::Repository,
+    pagination::Paginated,
+    path::segment,
+    Executor,
+};
 
 #[derive(Default, Debug, Clone, Serialize, Builder)]
 pub struct ListStarredBuilder {
@@ -36,10 +41,16 @@ impl ListStarredBuilder {
     }
 
     /// Sends the request to list the user's starred repos.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Repository>> {
-        let req = client.get("/user/starred").query(self).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<Repository>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -52,13 +63,17 @@ impl StarRepoBuilder {
     }
 
     /// Sends the request to star the repo.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let Self { owner, repo } = self;
-        let req = client
-            .put(format!("/user/starred/{owner}/{repo}"))
-            .build()?;
-        let _ = client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .put(format!("/user/starred/{owner}/{repo}"))
+                .build()?;
+            let _ = client.make_request(req).await?;
+            Ok(())
+        })
     }
 }
 
@@ -71,13 +86,17 @@ impl UnstarRepoBuilder {
     }
 
     /// Sends the request to star the repo.
-    pub async fn send(&self, client: &Client) -> Result<()> {
-        let Self { owner, repo } = self;
-        let req = client
-            .delete(format!("/user/starred/{owner}/{repo}"))
-            .build()?;
-        let _ = client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .delete(format!("/user/starred/{owner}/{repo}"))
+                .build()?;
+            let _ = client.make_request(req).await?;
+            Ok(())
+        })
     }
 }
 
@@ -90,20 +109,44 @@ impl IsStarredBuilder {
     }
 
     /// Sends the request to star the repo.
-    pub async fn send(&self, client: &Client) -> Result<bool> {
-        let Self { owner, repo } = self;
-        let req = client
-            .get(format!("/user/starred/{owner}/{repo}"))
-            .build()?;
-        match client.make_request(req).await {
-            Ok(_) => Ok(true),
-            Err(e) => {
-                if e.status_code == StatusCode::NOT_FOUND {
-                    Ok(false)
-                } else {
-                    Err(e)
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<bool>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let owner = segment(&self.owner);
+            let repo = segment(&self.repo);
+            let req = client
+                .get(format!("/user/starred/{owner}/{repo}"))
+                .build()?;
+            match client.make_request(req).await {
+                Ok(_) => Ok(true),
+                Err(e) => {
+                    if e.kind == TeatimeErrorKind::NotFound {
+                        Ok(false)
+                    } else {
+                        Err(e)
+                    }
                 }
             }
+        })
+    }
+}
+
+impl Paginated for ListStarredBuilder {
+    type Item = Repository;
+
+    fn path(&self) -> String {
+        "user/starred".to_string()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
         }
     }
 }
@@ -0,0 +1,50 @@
+use build_it::Builder;
+use serde::Serialize;
+
+use crate::{error::Result, model::team::Team, pagination::Paginated, Executor};
+
+#[derive(Debug, Default, Clone, Serialize, Builder)]
+#[build_it(into)]
+pub struct ListTeamsBuilder {
+    /// Page number of results to return (1-based).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    /// Page size of results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl ListTeamsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Send the request to list the authenticated user's teams.
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<Vec<Team>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListTeamsBuilder {
+    type Item = Team;
+
+    fn path(&self) -> String {
+        "user/teams".to_string()
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
+    }
+}
@@ -3,6 +3,9 @@ use serde::Serialize;
 
 use crate::error::Result;
 use crate::model::user::AccessToken;
+use crate::pagination::Paginated;
+use crate::path::segment;
+use crate::Executor;
 
 #[derive(Debug, Clone, Builder, Serialize)]
 pub struct ListAccessTokensBuilder {
@@ -44,14 +47,16 @@ impl ListAccessTokensBuilder {
         }
     }
     /// Sends the request to list access tokens.
-    pub async fn send(&self, client: &crate::Client) -> Result<Vec<AccessToken>> {
-        let username = &self.username;
-        let req = client
-            .get(format!("users/{username}/tokens"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<AccessToken>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -65,14 +70,17 @@ impl CreateAccessTokenBuilder {
     }
 
     /// Sends the request to create the access token.
-    pub async fn send(&self, client: &crate::Client) -> Result<AccessToken> {
-        let username = &self.user;
-        let req = client
-            .post(format!("users/{username}/tokens"))
-            .json(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<AccessToken>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let username = segment(&self.user);
+            let req = client
+                .post(format!("users/{username}/tokens"))
+                .json(self)
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
 
@@ -84,12 +92,36 @@ impl DeleteAccessTokenBuilder {
         }
     }
     /// Sends the request to delete the access token.
-    pub async fn send(&self, client: &crate::Client) -> Result<()> {
-        let DeleteAccessTokenBuilder { user, token } = self;
-        let req = client
-            .delete(format!("users/{user}/tokens/{token}"))
-            .build()?;
-        client.make_request(req).await?;
-        Ok(())
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<()>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let user = segment(&self.user);
+            let token = segment(&self.token);
+            let req = client
+                .delete(format!("users/{user}/tokens/{token}"))
+                .build()?;
+            client.make_request(req).await?;
+            Ok(())
+        })
+    }
+}
+
+impl Paginated for ListAccessTokensBuilder {
+    type Item = AccessToken;
+
+    fn path(&self) -> String {
+        format!("users/{}/tokens", segment(&self.username))
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
@@ -1,5 +1,7 @@
 use crate::error::Result;
 use crate::model::user::User;
+use crate::path::segment;
+use crate::Executor;
 
 pub struct GetUserBuilder {
     username: String,
@@ -11,9 +13,14 @@ impl GetUserBuilder {
             username: username.to_string(),
         }
     }
-    pub async fn send(&self, client: &crate::Client) -> Result<User> {
-        let req = client.get(format!("users/{}", self.username)).build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(&'a self, executor: &'a E) -> E::Output<'a, Result<User>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client
+                .get(format!("users/{}", segment(&self.username)))
+                .build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
     }
 }
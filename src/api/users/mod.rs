@@ -31,7 +31,9 @@ impl Users {
     /// # }
     /// ```
     /// This will get the user with the username "username".
-    /// If the user does not exist, this method will return a [TeatimeError] with a 404 status code.
+    /// If the user does not exist, this method will return a
+    /// [TeatimeError](crate::error::TeatimeError) of kind
+    /// [NotFound](crate::error::TeatimeErrorKind::NotFound).
     ///
     pub fn get(&self) -> get::GetUserBuilder {
         get::GetUserBuilder::new(&self.username)
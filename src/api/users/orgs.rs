@@ -1,9 +1,9 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{model::orgs::Organization, Client};
+use crate::{model::orgs::Organization, pagination::Paginated, path::segment, Executor};
 
-#[derive(Debug, Default, Builder, Serialize)]
+#[derive(Debug, Default, Clone, Builder, Serialize)]
 #[build_it(into)]
 pub struct Orgs {
     #[serde(skip)]
@@ -22,13 +22,36 @@ impl Orgs {
         }
     }
     /// Send the request to get the user's organizations.
-    pub async fn send(&self, client: &Client) -> crate::Result<Vec<Organization>> {
-        let username = &self.username;
-        let req = client
-            .get(format!("users/{username}/orgs"))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, crate::Result<Vec<Organization>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for Orgs {
+    type Item = Organization;
+
+    fn path(&self) -> String {
+        let username = segment(&self.username);
+        format!("users/{username}/orgs")
+    }
+    fn page(&self) -> Option<i64> {
+        self.page
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page),
+            ..self.clone()
+        }
     }
 }
@@ -1,9 +1,11 @@
 use build_it::Builder;
 use serde::Serialize;
 
-use crate::{error::Result, model::repos::Repository, Client};
+use crate::{
+    error::Result, model::repos::Repository, pagination::Paginated, path::segment, Executor,
+};
 
-#[derive(Default, Debug, Serialize, Builder)]
+#[derive(Default, Debug, Clone, Serialize, Builder)]
 #[build_it(into)]
 pub struct ListStarredBuilder {
     #[build_it(skip)]
@@ -28,12 +30,35 @@ impl ListStarredBuilder {
     }
 
     /// Sends the request to get the user's stars.
-    pub async fn send(&self, client: &Client) -> Result<Vec<Repository>> {
-        let req = client
-            .get(format!("/users/{}/starred", self.username))
-            .query(self)
-            .build()?;
-        let res = client.make_request(req).await?;
-        client.parse_response(res).await
+    pub fn send<'a, E: Executor>(
+        &'a self,
+        executor: &'a E,
+    ) -> E::Output<'a, Result<Vec<Repository>>> {
+        let client = executor.client();
+        executor.execute(async move {
+            let req = client.get(self.path()).query(self).build()?;
+            let res = client.make_request(req).await?;
+            client.parse_response(res).await
+        })
+    }
+}
+
+impl Paginated for ListStarredBuilder {
+    type Item = Repository;
+
+    fn path(&self) -> String {
+        format!("users/{}/starred", segment(&self.username))
+    }
+    fn page(&self) -> Option<i64> {
+        self.page.map(|n| n as i64)
+    }
+    fn limit(&self) -> Option<i64> {
+        self.limit.map(|n| n as i64)
+    }
+    fn with_page(&self, page: i64) -> Self {
+        Self {
+            page: Some(page as u64),
+            ..self.clone()
+        }
     }
 }
@@ -0,0 +1,101 @@
+//! A synchronous client, for programs that don't run an async runtime.
+//!
+//! A [blocking::Client](Client) works with the same builders and models as the async
+//! [Client](crate::Client), but their `send` methods block until the request is done and return
+//! its result directly.
+//!
+//! # Example
+//! ```
+//! # use gitea_sdk::{blocking, Auth};
+//! # fn get_repo() {
+//! let client = blocking::Client::new("https://gitea.example.com", Auth::Token("your-token"));
+//! let repo = client
+//!     .repos("owner", "repo")
+//!     .get()
+//!     .send(&client)
+//!     .unwrap();
+//! println!("{} has {} stars", repo.full_name, repo.stars_count);
+//! # }
+//! ```
+//!
+//! NOTE: A blocking client runs its own (single-threaded) tokio runtime. Don't use it from
+//! within async code: sending a request from inside another runtime panics.
+use std::future::Future;
+use std::ops::Deref;
+
+use crate::error::{Result, TeatimeError, TeatimeErrorKind};
+use crate::{Auth, ClientBuilder, Executor};
+
+/// A synchronous Gitea client. See the [module documentation](self).
+///
+/// This type dereferences to the async [Client](crate::Client), so all of its methods (like
+/// [repos](crate::Client::repos) or [user](crate::Client::user)) are available here as well.
+pub struct Client {
+    inner: crate::Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl Client {
+    /// Creates a new blocking client with the given base URL and authentication method.
+    /// See [Client::new](crate::Client::new) for details about the base URL.
+    ///
+    /// # Panics
+    /// Panics if [Client::new](crate::Client::new) would panic, or if the runtime could not be
+    /// created. Use [ClientBuilder::build_blocking] to handle these errors instead.
+    pub fn new(base_url: impl ToString, auth: Auth<impl ToString>) -> Self {
+        ClientBuilder::new(base_url)
+            .auth(auth)
+            .build_blocking()
+            .expect("client build error")
+    }
+
+    /// Wraps an async client in a blocking client.
+    pub fn from_async(client: crate::Client) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                TeatimeError::new(
+                    TeatimeErrorKind::InvalidConfig,
+                    format!("failed to start runtime: {e}"),
+                )
+            })?;
+        Ok(Self {
+            inner: client,
+            runtime,
+        })
+    }
+
+    /// Runs a future to completion on this client's runtime.
+    /// Use this to call async methods that have no blocking counterpart, like
+    /// [Paginated::send_all](crate::pagination::Paginated::send_all).
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+impl Deref for Client {
+    type Target = crate::Client;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl Executor for Client {
+    type Output<'a, T: 'a>
+        = T
+    where
+        Self: 'a;
+
+    fn client(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    fn execute<'a, T: 'a>(
+        &'a self,
+        request: impl Future<Output = T> + Send + 'a,
+    ) -> Self::Output<'a, T> {
+        self.block_on(request)
+    }
+}
@@ -1,38 +1,143 @@
 use core::fmt;
 use std::{error::Error, fmt::Display};
 
-use reqwest::StatusCode;
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TeatimeErrorKind {
+    /// The requested resource does not exist (404), or the authenticated user can't see it.
+    NotFound,
+    /// The request was not authenticated or the credentials are invalid (401).
+    Unauthorized,
+    /// The authenticated user (or token) lacks the permission or scope for the request (403).
+    Forbidden,
+    /// The request conflicts with the current state of the resource (409), for example when
+    /// creating a repository that already exists.
+    Conflict,
+    /// The request was well-formed, but Gitea rejected its contents (422).
+    /// [TeatimeError::api_error] usually contains details about the offending fields.
+    ValidationFailed,
+    /// Any other HTTP error status.
     HttpError,
+    /// The request timed out.
+    Timeout,
+    /// The connection to the Gitea instance could not be established.
+    Connect,
+    /// The response body could not be decoded into the expected model.
+    Decode,
     ParseError,
     SerializationError,
+    /// A [Client](crate::Client) could not be built from the given settings, for example because
+    /// of a malformed proxy URL or certificate.
+    InvalidConfig,
     Other,
 }
 
+impl TeatimeErrorKind {
+    /// Returns the error kind for an HTTP error status.
+    pub fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::NOT_FOUND => TeatimeErrorKind::NotFound,
+            StatusCode::UNAUTHORIZED => TeatimeErrorKind::Unauthorized,
+            StatusCode::FORBIDDEN => TeatimeErrorKind::Forbidden,
+            StatusCode::CONFLICT => TeatimeErrorKind::Conflict,
+            StatusCode::UNPROCESSABLE_ENTITY => TeatimeErrorKind::ValidationFailed,
+            _ => TeatimeErrorKind::HttpError,
+        }
+    }
+}
+
 impl Display for TeatimeErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            TeatimeErrorKind::NotFound => write!(f, "Not found"),
+            TeatimeErrorKind::Unauthorized => write!(f, "Unauthorized"),
+            TeatimeErrorKind::Forbidden => write!(f, "Forbidden"),
+            TeatimeErrorKind::Conflict => write!(f, "Conflict"),
+            TeatimeErrorKind::ValidationFailed => write!(f, "Validation failed"),
             TeatimeErrorKind::HttpError => write!(f, "HTTP error"),
+            TeatimeErrorKind::Timeout => write!(f, "Timeout"),
+            TeatimeErrorKind::Connect => write!(f, "Connection error"),
+            TeatimeErrorKind::Decode => write!(f, "Decoding error"),
             TeatimeErrorKind::ParseError => write!(f, "Parsing error"),
             TeatimeErrorKind::SerializationError => write!(f, "Serialization error"),
+            TeatimeErrorKind::InvalidConfig => write!(f, "Invalid configuration"),
             TeatimeErrorKind::Other => write!(f, "error"),
         }
     }
 }
 
+/// The error body Gitea returns for failed API requests.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiError {
+    /// Human-readable description of the error.
+    pub message: String,
+    /// Link to the API documentation.
+    pub url: String,
+    /// Additional error details, for example the fields that failed validation.
+    pub errors: Vec<String>,
+}
+
 /// Represents some kind of error that can occur when interacting with the Gitea API.
-/// This simply wraps a message and a status code.
+/// Use [TeatimeError::kind] to find out what went wrong without having to inspect the message.
 #[derive(Debug, Clone)]
 pub struct TeatimeError {
     pub message: String,
     pub kind: TeatimeErrorKind,
-    pub status_code: reqwest::StatusCode,
+    /// The HTTP status code of the response, if a response was received.
+    pub status_code: Option<StatusCode>,
+    /// The method of the request that failed, if known.
+    pub method: Option<Method>,
+    /// The URL path of the request that failed, if known.
+    pub path: Option<String>,
+    /// The error body returned by Gitea, if it could be parsed.
+    pub api_error: Option<Box<ApiError>>,
 }
+
+impl TeatimeError {
+    /// Creates a new error with the given kind and message and no request context.
+    pub fn new(kind: TeatimeErrorKind, message: impl ToString) -> Self {
+        TeatimeError {
+            message: message.to_string(),
+            kind,
+            status_code: None,
+            method: None,
+            path: None,
+            api_error: None,
+        }
+    }
+
+    /// Creates an error from an HTTP error response's status and body.
+    /// If the body is Gitea's JSON error format, it is parsed into [TeatimeError::api_error].
+    pub fn from_response(status: StatusCode, body: String) -> Self {
+        let api_error = serde_json::from_str::<ApiError>(&body).ok().map(Box::new);
+        let message = match &api_error {
+            Some(api) if !api.message.is_empty() => api.message.clone(),
+            _ => body,
+        };
+        TeatimeError {
+            status_code: Some(status),
+            api_error,
+            ..Self::new(TeatimeErrorKind::from_status(status), message)
+        }
+    }
+
+    /// Attaches the method and path of the failed request to the error.
+    pub fn with_request(mut self, method: &Method, path: impl ToString) -> Self {
+        self.method = Some(method.clone());
+        self.path = Some(path.to_string());
+        self
+    }
+}
+
 impl Error for TeatimeError {}
 impl Display for TeatimeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let (Some(method), Some(path)) = (&self.method, &self.path) {
+            write!(f, "{method} {path}: ")?;
+        }
         write!(f, "{}", self.message)
     }
 }
@@ -46,34 +151,35 @@ pub type Result<T> = std::result::Result<T, TeatimeError>;
 /// of a [reqwest::Result].
 impl From<reqwest::Error> for TeatimeError {
     fn from(err: reqwest::Error) -> Self {
-        let mut kind = TeatimeErrorKind::HttpError;
-        if err.is_decode() {
-            kind = TeatimeErrorKind::SerializationError;
-        }
+        let kind = if err.is_timeout() {
+            TeatimeErrorKind::Timeout
+        } else if err.is_connect() {
+            TeatimeErrorKind::Connect
+        } else if err.is_decode() {
+            TeatimeErrorKind::Decode
+        } else if err.is_builder() {
+            TeatimeErrorKind::SerializationError
+        } else if let Some(status) = err.status() {
+            TeatimeErrorKind::from_status(status)
+        } else {
+            TeatimeErrorKind::HttpError
+        };
         TeatimeError {
-            message: format!("{}", err),
-            status_code: err.status().unwrap_or(StatusCode::BAD_REQUEST),
-            kind,
+            status_code: err.status(),
+            path: err.url().map(|url| url.path().to_string()),
+            ..Self::new(kind, &err)
         }
     }
 }
 
 impl From<serde_json::Error> for TeatimeError {
     fn from(err: serde_json::Error) -> Self {
-        TeatimeError {
-            message: format!("{}", err),
-            status_code: StatusCode::BAD_REQUEST,
-            kind: TeatimeErrorKind::ParseError,
-        }
+        Self::new(TeatimeErrorKind::ParseError, err)
     }
 }
 
 impl From<Box<dyn Error>> for TeatimeError {
     fn from(err: Box<dyn Error>) -> Self {
-        TeatimeError {
-            message: format!("{}", err),
-            status_code: StatusCode::BAD_REQUEST,
-            kind: TeatimeErrorKind::Other,
-        }
+        Self::new(TeatimeErrorKind::Other, err)
     }
 }
@@ -0,0 +1,50 @@
+//! Running requests asynchronously or synchronously.
+//!
+//! Every builder's `send` method is generic over an [Executor], which decides what `send`
+//! returns:
+//! - With a [Client], `send` returns a future that you `.await`.
+//! - With a `blocking::Client` (requires the `blocking` feature),
+//!   `send` blocks until the request is done and returns its result directly.
+//!
+//! This way, the same builders and models work in both async and sync code.
+use std::future::Future;
+
+use futures_core::future::BoxFuture;
+
+use crate::Client;
+
+/// Something that can send requests built by this crate's builders.
+/// You normally don't need to use this trait directly, see the [module documentation](self).
+pub trait Executor {
+    /// What a builder's `send` method returns for a request that produces a `T`.
+    type Output<'a, T: 'a>
+    where
+        Self: 'a;
+
+    /// The async client used to build and send requests.
+    fn client(&self) -> &Client;
+
+    /// Runs the given request.
+    fn execute<'a, T: 'a>(
+        &'a self,
+        request: impl Future<Output = T> + Send + 'a,
+    ) -> Self::Output<'a, T>;
+}
+
+impl Executor for Client {
+    type Output<'a, T: 'a>
+        = BoxFuture<'a, T>
+    where
+        Self: 'a;
+
+    fn client(&self) -> &Client {
+        self
+    }
+
+    fn execute<'a, T: 'a>(
+        &'a self,
+        request: impl Future<Output = T> + Send + 'a,
+    ) -> Self::Output<'a, T> {
+        Box::pin(request)
+    }
+}
@@ -61,19 +61,34 @@
 //! # }
 //!
 //!
-use base64::engine::{GeneralPurpose, GeneralPurposeConfig};
-use base64::{alphabet, Engine};
 use error::{Result, TeatimeError};
 use std::fmt::Display;
+use std::sync::Arc;
 
-use reqwest::header::{self, HeaderMap, HeaderValue};
+use reqwest::header::{Entry, HeaderMap};
 use reqwest::{Method, Response};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use url::Url;
 
 pub mod error;
 
 pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod builder;
+pub mod executor;
 pub mod model;
+pub mod pagination;
+mod path;
+pub mod response;
+pub mod retry;
+pub mod transport;
+pub mod webhook;
+
+pub use builder::ClientBuilder;
+#[cfg(feature = "chrono")]
+pub use chrono;
+pub use executor::Executor;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct CreateAccessTokenOption {
@@ -96,52 +111,63 @@ pub enum Auth<D: ToString> {
 /// It provides methods for creating repositories, getting repositories, deleting repositories,
 /// and listing a repo's commits.
 pub struct Client {
+    /// Builds requests, which are then sent by the transport. Has the client's headers as
+    /// defaults, for requests sent straight from the request helpers.
     cli: reqwest::Client,
-    base_url: String,
+    transport: Arc<dyn transport::Transport>,
+    /// Headers added to every request, unless the request sets them itself.
+    headers: HeaderMap,
+    /// The root of the API, always ending with `/api/v1/`.
+    base_url: Url,
+    retry_policy: retry::RetryPolicy,
 }
 
 impl Client {
     /// Creates a new Gitea client with the given base URL and personal token.
-    /// NOTE: The base URL MUST not include the `/api/v1` path and should not contain any trailing
-    /// slashes. For example, `https://gitea.example.com` is a valid base URL, but
-    /// `https://gitea.example.com/` or `https://gitea.example.com/api/v1` are not.
+    ///
+    /// The base URL is the address of the Gitea web interface, for example
+    /// `https://gitea.example.com`, or `https://example.com/gitea` if Gitea is served from a
+    /// sub-path. Trailing slashes and an `/api/v1` suffix are ignored, so
+    /// `https://gitea.example.com/api/v1/` works just as well.
+    ///
+    /// # Panics
+    /// Panics if the base URL is not a valid `http` or `https` URL, if the credentials contain
+    /// characters that are not allowed in an HTTP header, or if the TLS backend could not be
+    /// initialized. Use [Client::builder] to handle these errors instead.
     pub fn new(base_url: impl ToString, auth: Auth<impl ToString>) -> Self {
-        let mut headers = HeaderMap::new();
-        match auth {
-            Auth::Token(token) => {
-                let token = HeaderValue::from_str(&format!("token {}", token.to_string()))
-                    .expect("token error");
-                headers.insert(header::AUTHORIZATION, token);
-            }
-            Auth::Basic(user, pass) => {
-                let engine = GeneralPurpose::new(&alphabet::STANDARD, GeneralPurposeConfig::new());
-                let base = engine.encode(format!("{}:{}", user.to_string(), pass.to_string()));
-                let basic =
-                    HeaderValue::from_str(&format!("Basic {base}")).expect("basic auth error");
-                headers.insert(header::AUTHORIZATION, basic);
-            }
-            Auth::None => {}
-        };
-        headers.insert(header::ACCEPT, HeaderValue::from_static("application/json"));
-
-        let cli = reqwest::ClientBuilder::new()
-            .default_headers(headers)
-            // reqwest 0.13 changed its default TLS backend from native-tls to rustls. Keep using
-            // the platform TLS implementation so upgrading reqwest does not change how existing
-            // Gitea installations and their certificates are handled.
-            .tls_backend_native()
-            .user_agent(format!(
-                "{}/{}",
-                env!("CARGO_PKG_NAME"),
-                env!("CARGO_PKG_VERSION")
-            ))
+        Self::builder(base_url)
+            .auth(auth)
             .build()
-            .expect("client build error");
+            .expect("client build error")
+    }
 
-        Self {
-            cli,
-            base_url: base_url.to_string(),
-        }
+    /// Creates a [ClientBuilder] to configure timeouts, a proxy, TLS settings or default headers
+    /// for a client with the given base URL.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// # use gitea_sdk::{Client, Auth};
+    /// # fn build_client() -> gitea_sdk::error::Result<()> {
+    /// let ca = std::fs::read("internal-ca.pem").unwrap();
+    /// let client = Client::builder("https://gitea.internal")
+    ///     .auth(Auth::Token("your-token"))
+    ///     .timeout(Duration::from_secs(60))
+    ///     .add_root_certificate(ca)
+    ///     .default_header("X-Request-Source", "ci")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder(base_url: impl ToString) -> ClientBuilder {
+        ClientBuilder::new(base_url)
+    }
+
+    /// Sets the [RetryPolicy](retry::RetryPolicy) used to retry failed requests.
+    /// By default, requests are not retried.
+    pub fn with_retry_policy(mut self, policy: retry::RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
     }
 
     pub fn repos(&self, owner: impl ToString, repo: impl ToString) -> api::repos::Repos {
@@ -209,6 +235,11 @@ impl Client {
         api::user::User
     }
 
+    /// Methods that require the authenticated user to be an administrator of the instance.
+    pub fn admin(&self) -> api::admin::Admin {
+        api::admin::Admin
+    }
+
     pub fn users(&self, username: impl ToString) -> api::users::Users {
         api::users::Users {
             username: username.to_string(),
@@ -221,61 +252,150 @@ impl Client {
         }
     }
 
+    /// Methods for a single team of an organization, like managing its members and
+    /// repositories.
+    pub fn teams(&self, id: i64) -> api::teams::Teams {
+        api::teams::Teams { id }
+    }
+
+    /// Methods for reading the label templates available on the instance.
+    /// Label templates are predefined sets of labels, like the ones offered when creating a
+    /// repository.
+    pub fn label_templates(&self) -> api::labels::LabelTemplates {
+        api::labels::LabelTemplates
+    }
+
     /// Creates a new DELETE-request builder with the given path.
     /// You may use this method to talk to the Gitea API directly if you need to.
-    /// `path` will be prefixed with `{base_url}/api/v1/` before the request is sent.
+    /// `path` will be appended to `{base_url}/api/v1/` before the request is sent.
     pub fn delete(&self, path: impl Display) -> reqwest::RequestBuilder {
         self.request_base(Method::DELETE, path)
     }
     /// Creates a new PATCH-request builder with the given path.
     /// You may use this method to talk to the Gitea API directly if you need to.
-    /// `path` will be prefixed with `{base_url}/api/v1/` before the request is sent.
+    /// `path` will be appended to `{base_url}/api/v1/` before the request is sent.
     pub fn patch(&self, path: impl Display) -> reqwest::RequestBuilder {
         self.request_base(Method::PATCH, path)
     }
     /// Creates a new POST-request builder with the given path.
     /// You may use this method to talk to the Gitea API directly if you need to.
-    /// `path` will be prefixed with `{base_url}/api/v1/` before the request is sent.
+    /// `path` will be appended to `{base_url}/api/v1/` before the request is sent.
     pub fn post(&self, path: impl Display) -> reqwest::RequestBuilder {
         self.request_base(Method::POST, path)
     }
     /// Creates a new POST-request builder with the given path.
     /// You may use this method to talk to the Gitea API directly if you need to.
-    /// `path` will be prefixed with `{base_url}/api/v1/` before the request is sent.
+    /// `path` will be appended to `{base_url}/api/v1/` before the request is sent.
     pub fn get(&self, path: impl Display) -> reqwest::RequestBuilder {
         self.request_base(Method::GET, path)
     }
     /// Creates a new PUT-request builder with the given path.
     /// You may use this method to talk to the Gitea API directly if you need to.
-    /// `path` will be prefixed with `{base_url}/api/v1/` before the request is sent.
+    /// `path` will be appended to `{base_url}/api/v1/` before the request is sent.
     pub fn put(&self, path: impl Display) -> reqwest::RequestBuilder {
         self.request_base(Method::PUT, path)
     }
 
     /// Creates a new request builder with the given method and path.
     /// You may use this method to talk to the Gitea API directly if you need to.
-    /// `path` will be prefixed with `{base_url}/api/v1/` before the request is sent.
+    /// `path` will be appended to `{base_url}/api/v1/` before the request is sent.
+    ///
+    /// The request gets the client's default headers (like `Authorization`) either way, but
+    /// only requests passed to [Client::make_request] go through the client's
+    /// [Transport](transport::Transport) and [RetryPolicy](retry::RetryPolicy). Calling `send`
+    /// on the returned builder sends the request with reqwest directly.
     pub fn request_base(&self, method: Method, path: impl Display) -> reqwest::RequestBuilder {
-        self.cli
-            .request(method, format!("{}/api/v1/{}", self.base_url, path))
+        self.cli.request(method, self.api_url(path))
+    }
+    /// Returns the full URL for an API path like `repos/owner/repo`.
+    /// Characters that are not allowed in a URL path (like spaces or `#`) are percent-encoded.
+    /// Anything after a `?` is used as the query string.
+    pub fn api_url(&self, path: impl Display) -> Url {
+        let path = path.to_string();
+        let (path, query) = match path.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (path.as_str(), None),
+        };
+        let mut url = self.base_url.clone();
+        url.set_path(&format!(
+            "{}{}",
+            self.base_url.path(),
+            path.trim_start_matches('/')
+        ));
+        url.set_query(query);
+        url
     }
-    /// Sends a request and checks the response for errors.
+    /// Sends a request through the client's [Transport](transport::Transport) and checks the
+    /// response for errors.
     /// You may use this method to talk to the Gitea API directly if you need to.
     /// This method will return a [TeatimeError] if the request fails.
+    /// If the request fails with a transient error, it is retried according to the client's
+    /// [RetryPolicy](retry::RetryPolicy).
     /// NOTE: This method is not recommended for general use. Use the more specific methods
     /// provided by the [Client] struct if they exist.
     /// You are responsible for providing the correct Model for the response.
-    pub async fn make_request(&self, req: reqwest::Request) -> Result<Response> {
-        let res = self.cli.execute(req).await?;
-        let status = res.status();
-        if status.is_client_error() || status.is_server_error() {
-            return Err(TeatimeError {
-                message: res.text().await.unwrap_or_default(),
-                kind: error::TeatimeErrorKind::HttpError,
-                status_code: status,
-            });
+    pub async fn make_request(&self, mut req: reqwest::Request) -> Result<Response> {
+        for (name, value) in &self.headers {
+            if let Entry::Vacant(entry) = req.headers_mut().entry(name) {
+                entry.insert(value.clone());
+            }
+        }
+        self.send_request(req).await
+    }
+    /// Sends a GET request to a URL Gitea returned, like the download URL of an attachment.
+    /// The client's headers, including its credentials, are only added if the URL has the same
+    /// scheme, host and port as the Gitea instance, so they aren't leaked to other hosts (for
+    /// example a CDN serving the files).
+    pub(crate) async fn get_url(&self, url: &str) -> Result<Response> {
+        let req = self.cli.get(url).build()?;
+        if req.url().origin() == self.base_url.origin() {
+            self.make_request(req).await
+        } else {
+            self.send_request(req).await
+        }
+    }
+    /// Sends a request through the transport as is, retrying it according to the retry policy.
+    async fn send_request(&self, mut req: reqwest::Request) -> Result<Response> {
+        let method = req.method().clone();
+        let path = req.url().path().to_string();
+        let policy = &self.retry_policy;
+        let mut attempt = 1;
+        loop {
+            // Requests with streaming bodies can't be cloned and are therefore never retried.
+            let retry = policy
+                .can_retry(&method, attempt)
+                .then(|| req.try_clone())
+                .flatten();
+            let res = match self.transport.send(req).await {
+                Ok(res) => res,
+                Err(e) => match retry {
+                    Some(next)
+                        if matches!(
+                            e.kind,
+                            error::TeatimeErrorKind::Connect | error::TeatimeErrorKind::Timeout
+                        ) =>
+                    {
+                        tokio::time::sleep(policy.delay(attempt, None)).await;
+                        req = next;
+                        attempt += 1;
+                        continue;
+                    }
+                    _ => return Err(e.with_request(&method, &path)),
+                },
+            };
+            let status = res.status();
+            if status.is_client_error() || status.is_server_error() {
+                if let Some(next) = retry.filter(|_| policy.retries_status(status)) {
+                    tokio::time::sleep(policy.delay(attempt, Some(res.headers()))).await;
+                    req = next;
+                    attempt += 1;
+                    continue;
+                }
+                let body = res.text().await.unwrap_or_default();
+                return Err(TeatimeError::from_response(status, body).with_request(&method, &path));
+            }
+            return Ok(res);
         }
-        Ok(res)
     }
     /// Parses a json response into a given model.
     /// You may use this method to talk to the Gitea API directly if you need to.
@@ -285,11 +405,29 @@ impl Client {
     /// You are responsible for providing the correct Model for the response.
     pub async fn parse_response<T: DeserializeOwned>(&self, res: reqwest::Response) -> Result<T> {
         let status_code = res.status();
+        let path = res.url().path().to_string();
         let text = res.text().await?;
         serde_json::from_str(&text).map_err(|e| TeatimeError {
-            message: format!("Error parsing response: {}", e),
-            kind: error::TeatimeErrorKind::SerializationError,
-            status_code,
+            status_code: Some(status_code),
+            path: Some(path),
+            ..TeatimeError::new(
+                error::TeatimeErrorKind::Decode,
+                format!("Error parsing response: {}", e),
+            )
         })
     }
+    /// Parses a json response into a given model and keeps the response's metadata (status code,
+    /// total count, pagination links and headers) alongside it.
+    /// You may use this method to talk to the Gitea API directly if you need to.
+    /// This method will return a [TeatimeError] if the response cannot be deserialized.
+    /// You are responsible for providing the correct Model for the response.
+    pub async fn parse_response_with_meta<T: DeserializeOwned>(
+        &self,
+        res: reqwest::Response,
+    ) -> Result<response::ApiResponse<T>> {
+        let status = res.status();
+        let headers = res.headers().clone();
+        let body = self.parse_response(res).await?;
+        Ok(response::ApiResponse::new(body, status, headers))
+    }
 }
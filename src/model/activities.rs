@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{issues::Comment, repos::Repository, user::User, Timestamp};
+
+/// Represents an entry of an activity feed, like a push or a merged pull request.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Activity {
+    /// The user who performed the action.
+    pub act_user: Option<User>,
+    pub act_user_id: i64,
+    /// The comment the activity is about, if any.
+    pub comment: Option<Comment>,
+    pub comment_id: i64,
+    /// Details of the activity. The format depends on [op_type](Self::op_type), e.g. for
+    /// [ActivityOpType::CommitRepo] it's a JSON object of the pushed commits.
+    pub content: String,
+    pub created: Timestamp,
+    pub id: i64,
+    /// Whether the activity is only visible to users with access to the repository.
+    pub is_private: bool,
+    pub op_type: ActivityOpType,
+    /// The full name of the ref the activity is about, like `refs/heads/main`.
+    pub ref_name: String,
+    pub repo: Option<Repository>,
+    pub repo_id: i64,
+    /// The ID of the user whose feed the activity belongs to.
+    pub user_id: i64,
+}
+
+/// The kind of action an [Activity] records.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityOpType {
+    CreateRepo,
+    RenameRepo,
+    StarRepo,
+    WatchRepo,
+    /// Commits were pushed to a branch.
+    CommitRepo,
+    CreateIssue,
+    CreatePullRequest,
+    TransferRepo,
+    PushTag,
+    CommentIssue,
+    MergePullRequest,
+    CloseIssue,
+    ReopenIssue,
+    ClosePullRequest,
+    ReopenPullRequest,
+    DeleteTag,
+    DeleteBranch,
+    MirrorSyncPush,
+    MirrorSyncCreate,
+    MirrorSyncDelete,
+    ApprovePullRequest,
+    RejectPullRequest,
+    CommentPull,
+    PublishRelease,
+    PullReviewDismissed,
+    PullRequestReadyForReview,
+    AutoMergePullRequest,
+    /// An action this version of the crate doesn't know about.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,309 @@
+//! A structured model of unified diffs, as returned by
+//! [Pulls::diff](crate::api::pulls::Pulls::diff) and [Pulls::patch](crate::api::pulls::Pulls::patch).
+//!
+//! # Example
+//! ```
+//! # use gitea_sdk::model::diff::{Diff, LineKind};
+//! let diff = Diff::parse(concat!(
+//!     "diff --git a/README.md b/README.md\n",
+//!     "--- a/README.md\n",
+//!     "+++ b/README.md\n",
+//!     "@@ -1,2 +1,2 @@\n",
+//!     " # teatime\n",
+//!     "-Old line\n",
+//!     "+New line\n",
+//! ));
+//! let file = &diff.files[0];
+//! assert_eq!(file.path(), "README.md");
+//! assert_eq!((file.additions(), file.deletions()), (1, 1));
+//! let added = &file.hunks[0].lines[2];
+//! assert_eq!(added.kind, LineKind::Added);
+//! assert_eq!(added.new_line, Some(2));
+//! ```
+
+/// A parsed unified diff, like the output of `git diff` or `git format-patch`.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Diff {
+    /// The changed files, in the order they appear in the diff.
+    /// With a patch of several commits, a file can appear more than once.
+    pub files: Vec<FileDiff>,
+}
+
+/// The changes to a single file.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    /// The path of the file before the change, `None` if the file was added.
+    pub old_path: Option<String>,
+    /// The path of the file after the change, `None` if the file was deleted.
+    pub new_path: Option<String>,
+    /// Whether the file is binary. Binary files have no hunks.
+    pub is_binary: bool,
+    /// The changed regions of the file.
+    pub hunks: Vec<Hunk>,
+}
+
+/// A changed region of a file.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    /// The first line of the region in the old file.
+    pub old_start: u32,
+    /// The number of lines of the region in the old file.
+    pub old_lines: u32,
+    /// The first line of the region in the new file.
+    pub new_start: u32,
+    /// The number of lines of the region in the new file.
+    pub new_lines: u32,
+    /// The text after the range header, usually the enclosing function or section.
+    pub section: String,
+    pub lines: Vec<Line>,
+}
+
+/// A single line of a hunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub kind: LineKind,
+    /// The line's text, without the leading `+`, `-` or space.
+    pub content: String,
+    /// The line number in the old file, `None` for added lines.
+    pub old_line: Option<u32>,
+    /// The line number in the new file, `None` for removed lines.
+    pub new_line: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    /// An unchanged line.
+    Context,
+    Added,
+    Removed,
+}
+
+impl FileDiff {
+    /// The path of the file after the change, or before it if the file was deleted.
+    pub fn path(&self) -> &str {
+        self.new_path
+            .as_deref()
+            .or(self.old_path.as_deref())
+            .unwrap_or_default()
+    }
+    /// The number of added lines.
+    pub fn additions(&self) -> usize {
+        self.count(LineKind::Added)
+    }
+    /// The number of removed lines.
+    pub fn deletions(&self) -> usize {
+        self.count(LineKind::Removed)
+    }
+    fn count(&self, kind: LineKind) -> usize {
+        self.hunks
+            .iter()
+            .flat_map(|hunk| &hunk.lines)
+            .filter(|line| line.kind == kind)
+            .count()
+    }
+}
+
+impl Diff {
+    /// Parses a unified diff in git's format.
+    /// Anything that isn't part of a file's diff (like the commit headers of a patch) is
+    /// skipped, so this never fails: text without any diffs results in an empty [Diff].
+    pub fn parse(text: &str) -> Self {
+        let mut files = Vec::new();
+        let mut lines = text.lines().peekable();
+        while let Some(line) = lines.next() {
+            let Some(header) = line.strip_prefix("diff --git ") else {
+                continue;
+            };
+            let (old_path, new_path) = split_git_header(header);
+            let mut file = FileDiff {
+                old_path,
+                new_path,
+                ..Default::default()
+            };
+
+            // Extended header lines, up to the first hunk.
+            while let Some(&line) = lines.peek() {
+                if line.starts_with("diff --git ") || line.starts_with("@@ ") {
+                    break;
+                }
+                lines.next();
+                if line.starts_with("new file mode") {
+                    file.old_path = None;
+                } else if line.starts_with("deleted file mode") {
+                    file.new_path = None;
+                } else if let Some(path) = line
+                    .strip_prefix("rename from ")
+                    .or(line.strip_prefix("copy from "))
+                {
+                    file.old_path = Some(unquote(path));
+                } else if let Some(path) = line
+                    .strip_prefix("rename to ")
+                    .or(line.strip_prefix("copy to "))
+                {
+                    file.new_path = Some(unquote(path));
+                } else if line.starts_with("Binary files ") || line == "GIT binary patch" {
+                    file.is_binary = true;
+                } else if let Some(old) = line.strip_prefix("--- ") {
+                    if let Some(new) = lines.peek().and_then(|l| l.strip_prefix("+++ ")) {
+                        file.old_path = diff_path(old);
+                        file.new_path = diff_path(new);
+                        lines.next();
+                    }
+                }
+            }
+
+            while let Some(mut hunk) = lines.peek().and_then(|line| parse_hunk_header(line)) {
+                lines.next();
+                let (mut old_line, mut new_line) = (hunk.old_start, hunk.new_start);
+                let (mut old_left, mut new_left) = (hunk.old_lines, hunk.new_lines);
+                while old_left > 0 || new_left > 0 {
+                    let Some(line) = lines.next() else {
+                        break;
+                    };
+                    let content = line.get(1..).unwrap_or_default().to_string();
+                    let line = match line.as_bytes().first() {
+                        Some(b'+') => {
+                            new_left = new_left.saturating_sub(1);
+                            new_line += 1;
+                            Line {
+                                kind: LineKind::Added,
+                                content,
+                                old_line: None,
+                                new_line: Some(new_line - 1),
+                            }
+                        }
+                        Some(b'-') => {
+                            old_left = old_left.saturating_sub(1);
+                            old_line += 1;
+                            Line {
+                                kind: LineKind::Removed,
+                                content,
+                                old_line: Some(old_line - 1),
+                                new_line: None,
+                            }
+                        }
+                        // "\ No newline at end of file"
+                        Some(b'\\') => continue,
+                        _ => {
+                            old_left = old_left.saturating_sub(1);
+                            new_left = new_left.saturating_sub(1);
+                            old_line += 1;
+                            new_line += 1;
+                            Line {
+                                kind: LineKind::Context,
+                                content,
+                                old_line: Some(old_line - 1),
+                                new_line: Some(new_line - 1),
+                            }
+                        }
+                    };
+                    hunk.lines.push(line);
+                }
+                while lines.next_if(|line| line.starts_with('\\')).is_some() {}
+                file.hunks.push(hunk);
+            }
+            files.push(file);
+        }
+        Self { files }
+    }
+}
+
+/// Parses a hunk header like `@@ -1,4 +1,5 @@ fn main() {`.
+fn parse_hunk_header(line: &str) -> Option<Hunk> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (ranges, section) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let (old_start, old_lines) = parse_range(old)?;
+    let (new_start, new_lines) = parse_range(new)?;
+    Some(Hunk {
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        section: section.strip_prefix(' ').unwrap_or(section).to_string(),
+        lines: Vec::new(),
+    })
+}
+
+/// Parses a range like `1,4`. The length defaults to 1 if it's omitted.
+fn parse_range(range: &str) -> Option<(u32, u32)> {
+    match range.split_once(',') {
+        Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+/// Splits the paths of a `diff --git a/old b/new` line.
+/// The paths are ambiguous if they contain spaces, so they are only used until the `---` and
+/// `+++` lines (or the rename lines) are found.
+fn split_git_header(header: &str) -> (Option<String>, Option<String>) {
+    let (old, new) = if let Some((old, new)) = header
+        .strip_prefix('"')
+        .and_then(|header| header.split_once("\" "))
+    {
+        (format!("\"{old}\""), new.to_string())
+    } else {
+        // Unchanged paths are the most common case and can be split in the middle, even if
+        // they contain " b/".
+        let mid = header.len() / 2;
+        match header.get(..mid).zip(header.get(mid..)) {
+            Some((old, new)) if new.starts_with(" b/") && old.get(2..) == new.get(3..) => {
+                (old.to_string(), new[1..].to_string())
+            }
+            _ => match header.rsplit_once(" b/") {
+                Some((old, new)) => (old.to_string(), format!("b/{new}")),
+                None => (header.to_string(), header.to_string()),
+            },
+        }
+    };
+    (diff_path(&old), diff_path(&new))
+}
+
+/// Parses the path of a `---` or `+++` line, stripping the `a/` or `b/` prefix.
+fn diff_path(path: &str) -> Option<String> {
+    let path = unquote(path.trim_end_matches('\t'));
+    if path == "/dev/null" {
+        return None;
+    }
+    let path = path
+        .strip_prefix("a/")
+        .or(path.strip_prefix("b/"))
+        .map(str::to_string)
+        .unwrap_or(path);
+    Some(path)
+}
+
+/// Removes the quotes git adds around paths with special characters, and resolves their escape
+/// sequences (including octal escapes of non-ASCII bytes).
+fn unquote(path: &str) -> String {
+    let Some(inner) = path
+        .strip_prefix('"')
+        .and_then(|path| path.strip_suffix('"'))
+    else {
+        return path.to_string();
+    };
+    let mut bytes = Vec::with_capacity(inner.len());
+    let mut chars = inner.bytes().peekable();
+    while let Some(c) = chars.next() {
+        if c != b'\\' {
+            bytes.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(b'n') => bytes.push(b'\n'),
+            Some(b't') => bytes.push(b'\t'),
+            Some(d @ b'0'..=b'7') => {
+                let mut value = d - b'0';
+                for _ in 0..2 {
+                    if let Some(d) = chars.next_if(|d| (b'0'..=b'7').contains(d)) {
+                        value = value.wrapping_mul(8) + (d - b'0');
+                    }
+                }
+                bytes.push(value);
+            }
+            Some(c) => bytes.push(c),
+            None => bytes.push(b'\\'),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
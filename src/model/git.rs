@@ -0,0 +1,141 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Result, TeatimeError, TeatimeErrorKind},
+    model::repos::{Commit, CommitUser},
+};
+
+/// The type of a git object.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GitObjectType {
+    #[default]
+    Commit,
+    Tree,
+    Blob,
+    Tag,
+    /// A type this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A tree (directory listing) of a git repository.
+/// Large trees are paginated, see [truncated](Self::truncated).
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitTree {
+    /// The page of entries returned.
+    pub page: i64,
+    /// The SHA of the tree.
+    pub sha: String,
+    /// The entries of the tree (on this page).
+    pub tree: Vec<GitEntry>,
+    /// The total number of entries of the tree.
+    pub total_count: i64,
+    /// Whether there are more entries than returned.
+    pub truncated: bool,
+    /// The API endpoint for the tree.
+    pub url: String,
+}
+
+/// An entry of a [GitTree], a file, directory or submodule.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitEntry {
+    /// The file mode, like `100644` for regular files or `040000` for directories.
+    pub mode: String,
+    /// The path of the entry, relative to the tree.
+    pub path: String,
+    /// The SHA of the entry's object.
+    pub sha: String,
+    /// The size of blobs in bytes, 0 for other types.
+    pub size: i64,
+    #[serde(rename = "type")]
+    pub object_type: GitObjectType,
+    /// The API endpoint for the entry's object.
+    pub url: String,
+}
+
+/// The contents of a file in a git repository.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitBlob {
+    /// The encoded contents. See [decode](Self::decode).
+    pub content: String,
+    /// The encoding of the contents, usually `base64`.
+    pub encoding: String,
+    /// The SHA of the blob.
+    pub sha: String,
+    /// The size of the decoded contents in bytes.
+    pub size: i64,
+    /// The API endpoint for the blob.
+    pub url: String,
+}
+
+impl GitBlob {
+    /// Decodes the blob's base64 encoded contents.
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        let content: String = self
+            .content
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect();
+        BASE64_STANDARD.decode(content).map_err(|e| {
+            TeatimeError::new(
+                TeatimeErrorKind::Decode,
+                format!("Error decoding blob: {}", e),
+            )
+        })
+    }
+}
+
+/// A git reference, like a branch (`refs/heads/main`) or a tag (`refs/tags/v1.0`).
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Reference {
+    /// The object the reference points to.
+    pub object: GitObject,
+    /// The full name of the reference.
+    pub r#ref: String,
+    /// The API endpoint for the reference.
+    pub url: String,
+}
+
+/// A git object referenced by a [Reference] or an [AnnotatedTag].
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitObject {
+    pub sha: String,
+    #[serde(rename = "type")]
+    pub object_type: GitObjectType,
+    /// The API endpoint for the object.
+    pub url: String,
+}
+
+/// An annotated tag, a tag object with a message and a tagger.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnnotatedTag {
+    /// The tag's message.
+    pub message: String,
+    /// The object that is tagged, usually a commit.
+    pub object: GitObject,
+    /// The SHA of the tag object.
+    pub sha: String,
+    /// The name of the tag.
+    pub tag: String,
+    /// The person who created the tag.
+    pub tagger: CommitUser,
+    /// The API endpoint for the tag object.
+    pub url: String,
+}
+
+/// A git note attached to a commit.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Note {
+    /// The commit the note is attached to.
+    pub commit: Commit,
+    pub message: String,
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::Timestamp;
+
+/// The kind of service a webhook delivers to.
+/// Each type formats its payloads for that service.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HookType {
+    /// Gitea's own payload format, for your own services.
+    #[default]
+    Gitea,
+    Gogs,
+    Slack,
+    Discord,
+    Dingtalk,
+    Telegram,
+    Msteams,
+    Feishu,
+    Matrix,
+    Wechatwork,
+    Packagist,
+    /// A type this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The format a webhook's payload is sent in.
+/// Only used by [HookType::Gitea] and [HookType::Gogs] webhooks.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HookContentType {
+    /// `application/json`
+    #[default]
+    Json,
+    /// `application/x-www-form-urlencoded`, with the JSON payload in the `payload` field.
+    Form,
+}
+
+impl HookContentType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            HookContentType::Json => "json",
+            HookContentType::Form => "form",
+        }
+    }
+}
+
+/// Represents a webhook of a repository, an organization, a user or the instance.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Hook {
+    /// Whether the webhook is delivered.
+    pub active: bool,
+    /// The `Authorization` header sent with each delivery.
+    pub authorization_header: String,
+    /// A glob pattern of the branches push, branch creation and branch deletion events are
+    /// delivered for.
+    pub branch_filter: String,
+    /// The webhook's configuration, like its `url` and `content_type`.
+    /// Which keys exist depends on the [type](Hook::type).
+    /// The secret is never returned.
+    pub config: HashMap<String, String>,
+    pub created_at: Timestamp,
+    /// The events the webhook is delivered for, like `push` or `pull_request`.
+    pub events: Vec<String>,
+    pub id: i64,
+    pub r#type: HookType,
+    pub updated_at: Timestamp,
+}
+
+/// Represents a server-side git hook of a repository, like `pre-receive`.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitHook {
+    /// The script that is run.
+    pub content: String,
+    /// Whether the hook has content and is run.
+    pub is_active: bool,
+    /// The name of the hook, like `pre-receive`, `update` or `post-receive`.
+    pub name: String,
+}
@@ -2,7 +2,7 @@ use std::fmt::{self, Display};
 
 use serde::{Deserialize, Serialize};
 
-use crate::model::user::User;
+use crate::model::{user::User, Timestamp};
 
 /// Represents an attachment.
 /// Attachments are used in issues, pull requests, and releases.
@@ -10,7 +10,7 @@ use crate::model::user::User;
 #[serde(default)]
 pub struct Attachment {
     pub browser_download_url: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
     pub download_count: i64,
     pub id: i64,
     pub name: String,
@@ -32,6 +32,18 @@ pub struct Label {
     pub url: String,
 }
 
+/// Represents a label of a label template.
+/// Label templates are predefined sets of labels that can be used to initialize a repository's
+/// labels.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LabelTemplate {
+    pub color: String,
+    pub description: String,
+    pub exclusive: bool,
+    pub name: String,
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 /// Represents the state of an issue.
 pub enum State {
@@ -61,38 +73,64 @@ pub struct Issue {
     pub assignee: Option<User>,
     pub assignees: Option<Vec<User>>,
     pub body: Option<String>,
-    pub closed_at: Option<String>,
+    #[serde(default, with = "crate::model::timestamp::lenient_option")]
+    pub closed_at: Option<Timestamp>,
     pub comments: i64,
-    pub created_at: String,
-    pub due_date: Option<String>,
+    pub created_at: Timestamp,
+    #[serde(default, with = "crate::model::timestamp::lenient_option")]
+    pub due_date: Option<Timestamp>,
     pub html_url: String,
     pub id: i64,
     pub is_locked: bool,
     pub labels: Vec<Label>,
+    pub milestone: Option<Milestone>,
     pub number: i64,
     pub original_author: String,
     pub original_author_id: i64,
     pub pin_order: i64,
     pub r#ref: String,
     pub state: StateType,
-    pub updated_at: String,
+    pub updated_at: Timestamp,
     pub title: String,
     pub url: String,
     pub user: User,
 }
 
+/// Represents a milestone in a repository.
+/// Milestones group issues and pull requests, for example by release or sprint.
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Milestone {
+    #[serde(default, with = "crate::model::timestamp::lenient_option")]
+    pub closed_at: Option<Timestamp>,
+    /// Number of closed issues and pull requests in the milestone.
+    pub closed_issues: i64,
+    pub created_at: Timestamp,
+    pub description: String,
+    #[serde(default, with = "crate::model::timestamp::lenient_option")]
+    pub due_on: Option<Timestamp>,
+    pub id: i64,
+    /// Number of open issues and pull requests in the milestone.
+    pub open_issues: i64,
+    pub state: StateType,
+    pub title: String,
+    #[serde(default, with = "crate::model::timestamp::lenient_option")]
+    pub updated_at: Option<Timestamp>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Comment {
     pub assets: Vec<Attachment>,
     pub body: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
     pub html_url: String,
     pub id: i64,
     pub issue_url: String,
     pub original_author: String,
     pub original_author_id: i64,
     pub pull_request_url: String,
-    pub updated_at: String,
+    pub updated_at: Timestamp,
     pub user: User,
 }
 
@@ -1,7 +1,16 @@
+pub mod activities;
+pub mod diff;
+pub mod git;
+pub mod hooks;
 pub mod issues;
 pub mod orgs;
 pub mod pulls;
+pub mod releases;
 pub mod repos;
-pub mod user;
 pub mod reviews;
+pub mod statuses;
 pub mod team;
+pub mod timestamp;
+pub mod user;
+
+pub use timestamp::Timestamp;
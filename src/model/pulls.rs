@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 use super::{
-    issues::{Label, StateType},
+    issues::{Label, Milestone, StateType},
     repos::Repository,
+    team::Team,
     user::User,
+    Timestamp,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,14 +18,16 @@ pub struct PullRequest {
     pub body: String,
     #[serde(default)]
     pub changed_files: i64,
-    pub closed_at: Option<String>,
+    #[serde(default, with = "crate::model::timestamp::lenient_option")]
+    pub closed_at: Option<Timestamp>,
     pub comments: i64,
-    pub created_at: String,
+    pub created_at: Timestamp,
     #[serde(default)]
     pub deletions: i64,
     pub diff_url: String,
     pub draft: bool,
-    pub due_date: Option<String>,
+    #[serde(default, with = "crate::model::timestamp::lenient_option")]
+    pub due_date: Option<Timestamp>,
     pub head: PrBranchInfo,
     pub html_url: String,
     pub id: i64,
@@ -33,21 +37,23 @@ pub struct PullRequest {
     pub merge_commit_sha: Option<String>,
     pub mergeable: bool,
     pub merged: bool,
-    pub merged_at: Option<String>,
+    #[serde(default, with = "crate::model::timestamp::lenient_option")]
+    pub merged_at: Option<Timestamp>,
     pub merged_by: Option<User>,
-    // TODO: pub milestone: Option<Milestone>,
+    pub milestone: Option<Milestone>,
     pub number: i64,
     pub patch_url: String,
     pub pin_order: i64,
     pub requested_reviewers: Option<Vec<Option<User>>>,
+    /// The teams whose review was requested.
+    pub requested_reviewers_teams: Option<Vec<Team>>,
     /// Number of review comments made on the diff of a PR review (not including comments on commits or issues in a PR)
     // Gitea has this marked as `omitempty` so it's omitted when zero.
     #[serde(default)]
     pub review_comments: i64,
     pub state: StateType,
     pub title: String,
-    // TODO: Make this a DateTime<Utc>
-    pub updated_at: String,
+    pub updated_at: Timestamp,
     pub url: String,
     pub user: User,
 }
@@ -71,3 +77,103 @@ pub enum Sort {
     LeastComment,
     Priority,
 }
+
+/// How a pull request is merged.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeStyle {
+    /// Create a merge commit.
+    #[default]
+    Merge,
+    /// Rebase the head branch onto the base branch, then fast-forward.
+    Rebase,
+    /// Rebase the head branch onto the base branch, then create a merge commit.
+    RebaseMerge,
+    /// Squash all commits into a single commit.
+    Squash,
+    /// Only fast-forward the base branch, fail if that's not possible.
+    FastForwardOnly,
+    /// Mark the pull request as merged, because it was already merged outside of Gitea.
+    ManuallyMerged,
+}
+
+/// How a pull request's head branch is brought up to date with its base branch.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateStyle {
+    /// Merge the base branch into the head branch.
+    #[default]
+    Merge,
+    /// Rebase the head branch onto the base branch.
+    Rebase,
+}
+
+/// The outcome of a successful merge request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeResult {
+    /// The pull request was merged.
+    Merged,
+    /// The merge was scheduled and happens once all checks succeed.
+    /// Only returned if `merge_when_checks_succeed` was set.
+    Scheduled,
+}
+
+/// The format of a pull request's changes.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffType {
+    /// A unified diff of all changes, like `git diff`.
+    #[default]
+    Diff,
+    /// The pull request's commits as patches, like `git format-patch`.
+    Patch,
+}
+
+impl DiffType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            DiffType::Diff => "diff",
+            DiffType::Patch => "patch",
+        }
+    }
+}
+
+/// How a file was changed by a pull request.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    Added,
+    Deleted,
+    #[default]
+    Modified,
+    Renamed,
+    Copied,
+    Changed,
+    Unchanged,
+    /// A status this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Represents a file changed by a pull request.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChangedFile {
+    /// The number of added lines.
+    pub additions: i64,
+    /// The number of added and deleted lines.
+    pub changes: i64,
+    /// The API endpoint for the file's contents at the head of the pull request.
+    pub contents_url: String,
+    /// The number of deleted lines.
+    pub deletions: i64,
+    /// The path of the file.
+    pub filename: String,
+    /// The URL to the file on the Gitea instance.
+    pub html_url: String,
+    /// The path of the file before it was renamed.
+    /// Only set if [status](Self::status) is [FileStatus::Renamed].
+    pub previous_filename: Option<String>,
+    /// The URL to the file's raw contents.
+    pub raw_url: String,
+    pub status: FileStatus,
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{issues::Attachment, user::User, Timestamp};
+
+/// Represents a release of a repository.
+/// A release is attached to a tag and can have any number of files (assets) attached to it.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Release {
+    /// Files attached to the release.
+    pub assets: Vec<Attachment>,
+    /// The user who created the release.
+    pub author: User,
+    /// The release notes.
+    pub body: String,
+    pub created_at: Timestamp,
+    /// Whether the release is a draft. Drafts are only visible to users with write access.
+    pub draft: bool,
+    /// The URL to the release on the Gitea instance.
+    pub html_url: String,
+    pub id: i64,
+    /// The title of the release.
+    pub name: String,
+    /// Whether the release is marked as a pre-release.
+    pub prerelease: bool,
+    pub published_at: Timestamp,
+    /// The name of the tag the release is attached to.
+    pub tag_name: String,
+    /// The URL to download the source code as a tarball.
+    pub tarball_url: String,
+    /// The branch or commit the tag is created from, if it doesn't exist yet.
+    pub target_commitish: String,
+    /// The API endpoint to upload assets to.
+    pub upload_url: String,
+    /// The API endpoint for the release.
+    pub url: String,
+    /// The URL to download the source code as a zip archive.
+    pub zipball_url: String,
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::model::user::User;
+use crate::model::{team::Permission, user::User, Timestamp};
 
 /// Represents the format of the object in the repository.
 /// Defaults to [ObjectFormatName::SHA1].
@@ -48,10 +48,10 @@ pub struct Repository {
     pub allow_rebase_update: bool,
     pub allow_squash_merge: bool,
     pub archived: bool,
-    pub archived_at: String,
+    pub archived_at: Timestamp,
     pub avatar_url: String,
     pub clone_url: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
     pub default_allow_maintainer_edit: bool,
     pub default_branch: String,
     pub default_delete_branch_after_merge: bool,
@@ -79,7 +79,7 @@ pub struct Repository {
     pub link: String,
     pub mirror: bool,
     pub mirror_interval: String,
-    pub mirror_updated: String,
+    pub mirror_updated: Timestamp,
     pub name: String,
     pub object_format_name: ObjectFormatName,
     pub open_issues_count: i64,
@@ -92,7 +92,10 @@ pub struct Repository {
     pub ssh_url: String,
     pub stars_count: i64,
     pub template: bool,
-    pub updated_at: String,
+    /// The topics the repository is tagged with.
+    /// Gitea sends `null` instead of an empty list.
+    pub topics: Option<Vec<String>>,
+    pub updated_at: Timestamp,
     pub url: String,
     pub watchers_count: i64,
     pub website: String,
@@ -108,7 +111,7 @@ pub struct Repository {
 #[serde(default)]
 pub struct CommitUser {
     /// Date the commit was authored.
-    pub date: String,
+    pub date: Timestamp,
     /// Email of the user.
     pub email: String,
     /// Full name of the user.
@@ -159,6 +162,7 @@ pub struct Commit {
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PayloadUser {
     pub email: String,
     /// Full name of the user.
@@ -167,6 +171,7 @@ pub struct PayloadUser {
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PayloadCommit {
     pub author: PayloadUser,
     pub committer: PayloadUser,
@@ -176,7 +181,7 @@ pub struct PayloadCommit {
     pub added: Option<Vec<String>>,
     pub modified: Option<Vec<String>>,
     pub removed: Option<Vec<String>>,
-    pub timestamp: String,
+    pub timestamp: Timestamp,
     pub url: String,
     // TODO: pub verification: PayloadCommitVerification,
 }
@@ -307,3 +312,135 @@ pub struct EntryMutation {
     /// Mutation verification
     verification: EntryVerification,
 }
+
+/// Represents a tag of a repository.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Tag {
+    /// The tagged commit.
+    /// NOTE: Only the `sha` and `url` fields are set.
+    pub commit: Commit,
+    /// The SHA of the tag object for annotated tags, or of the commit for lightweight tags.
+    pub id: String,
+    /// The message of an annotated tag. Empty for lightweight tags.
+    pub message: String,
+    pub name: String,
+    /// The URL to download the repository at this tag as a `.tar.gz` archive.
+    pub tarball_url: String,
+    /// The URL to download the repository at this tag as a `.zip` archive.
+    pub zipball_url: String,
+}
+
+/// Represents a tag protection rule.
+/// Tags matching a protected pattern can only be created or deleted by the allowlisted users
+/// and teams.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TagProtection {
+    pub created_at: Timestamp,
+    pub id: i64,
+    /// The tag names the rule applies to, as a glob (like `v*`) or a regular expression
+    /// enclosed in slashes (like `/^v\d+$/`).
+    pub name_pattern: String,
+    pub updated_at: Timestamp,
+    /// The names of the teams that may create and delete matching tags.
+    pub whitelist_teams: Vec<String>,
+    /// The usernames of the users that may create and delete matching tags.
+    pub whitelist_usernames: Vec<String>,
+}
+
+/// Represents a branch protection rule.
+/// A rule applies to the branches matching its [rule_name](Self::rule_name).
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BranchProtection {
+    /// The name of the rule, which is also the branch name or glob pattern (like `release/*`)
+    /// the rule applies to.
+    pub rule_name: String,
+    /// The priority of the rule if several rules match a branch. Higher values take precedence.
+    pub priority: i64,
+    /// Whether pushing to matching branches is allowed at all.
+    pub enable_push: bool,
+    /// Whether only the allowlisted users, teams and deploy keys may push.
+    pub enable_push_whitelist: bool,
+    /// The usernames of the users that may push.
+    pub push_whitelist_usernames: Option<Vec<String>>,
+    /// The names of the teams that may push.
+    pub push_whitelist_teams: Option<Vec<String>>,
+    /// Whether deploy keys with write access may push.
+    pub push_whitelist_deploy_keys: bool,
+    /// Whether force-pushing to matching branches is allowed at all.
+    pub enable_force_push: bool,
+    /// Whether only the allowlisted users, teams and deploy keys may force-push.
+    pub enable_force_push_allowlist: bool,
+    /// The usernames of the users that may force-push.
+    pub force_push_allowlist_usernames: Option<Vec<String>>,
+    /// The names of the teams that may force-push.
+    pub force_push_allowlist_teams: Option<Vec<String>>,
+    /// Whether deploy keys with write access may force-push.
+    pub force_push_allowlist_deploy_keys: bool,
+    /// Whether only the allowlisted users and teams may merge pull requests.
+    pub enable_merge_whitelist: bool,
+    /// The usernames of the users that may merge pull requests.
+    pub merge_whitelist_usernames: Option<Vec<String>>,
+    /// The names of the teams that may merge pull requests.
+    pub merge_whitelist_teams: Option<Vec<String>>,
+    /// Whether status checks must pass before pull requests can be merged.
+    pub enable_status_check: bool,
+    /// The contexts of the status checks that must pass, like `ci/build`. Glob patterns are
+    /// allowed.
+    pub status_check_contexts: Option<Vec<String>>,
+    /// The number of approvals a pull request needs before it can be merged.
+    pub required_approvals: i64,
+    /// Whether only approvals of the allowlisted users and teams count.
+    pub enable_approvals_whitelist: bool,
+    /// The usernames of the users whose approvals count.
+    pub approvals_whitelist_username: Option<Vec<String>>,
+    /// The names of the teams whose approvals count.
+    pub approvals_whitelist_teams: Option<Vec<String>>,
+    /// Whether merging is blocked while changes are requested by an official reviewer.
+    pub block_on_rejected_reviews: bool,
+    /// Whether merging is blocked while official review requests are pending.
+    pub block_on_official_review_requests: bool,
+    /// Whether merging is blocked while the head branch is behind the base branch.
+    pub block_on_outdated_branch: bool,
+    /// Whether approvals are dismissed when new commits are pushed.
+    pub dismiss_stale_approvals: bool,
+    /// Whether approvals of older commits don't count towards the required approvals.
+    pub ignore_stale_approvals: bool,
+    /// Whether all commits must be signed.
+    pub require_signed_commits: bool,
+    /// Semicolon-separated glob patterns of files that can't be changed, like `*.lock;docs/**`.
+    pub protected_file_patterns: String,
+    /// Semicolon-separated glob patterns of files that can be changed even by users who may not
+    /// push, like `CHANGELOG.md`.
+    pub unprotected_file_patterns: String,
+    /// Whether administrators must follow the rule too when merging.
+    pub block_admin_merge_override: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+/// The permission a user has in a repository.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepoCollaboratorPermission {
+    /// The user's effective permission, from being a collaborator, a team member or the owner.
+    pub permission: Permission,
+    /// The name of the user's role, like `owner` or `write`.
+    pub role_name: String,
+    pub user: User,
+}
+
+/// A topic that repositories can be tagged with, as returned by the topic search.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Topic {
+    pub id: i64,
+    /// The name of the topic, like `rust`.
+    pub topic_name: String,
+    /// The number of repositories tagged with the topic.
+    pub repo_count: i64,
+    pub created: Timestamp,
+    pub updated: Timestamp,
+}
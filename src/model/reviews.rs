@@ -1,8 +1,5 @@
+use super::{team::Team, user::User, Timestamp};
 use serde::{Deserialize, Serialize};
-use super::{
-    team::Team,
-    user::User,
-};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PullReview {
@@ -16,13 +13,13 @@ pub struct PullReview {
     pub pull_request_url: String,
     pub stale: bool,
     pub state: ReviewStateType,
-    pub submitted_at: String,
+    pub submitted_at: Timestamp,
     pub team: Option<Team>,
-    pub updated_at: String,
+    pub updated_at: Timestamp,
     pub user: Option<User>,
 }
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReviewStateType {
     #[default]
     #[serde(rename = "APPROVED")]
@@ -38,3 +35,66 @@ pub enum ReviewStateType {
     #[serde(rename = "UNKNOWN")]
     Unknown,
 }
+
+/// Represents a line-level comment of a pull request review.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewComment {
+    pub body: String,
+    /// The SHA of the commit the comment currently applies to.
+    pub commit_id: String,
+    pub created_at: Timestamp,
+    /// The part of the diff the comment refers to.
+    pub diff_hunk: String,
+    pub html_url: String,
+    pub id: i64,
+    /// The SHA of the commit the comment was made on.
+    pub original_commit_id: String,
+    /// The line the comment was made on, in the old version of the file.
+    pub original_position: u64,
+    /// The path of the commented file.
+    pub path: String,
+    /// The line the comment was made on, in the new version of the file.
+    pub position: u64,
+    /// The ID of the review the comment belongs to.
+    pub pull_request_review_id: i64,
+    pub pull_request_url: String,
+    /// The user who resolved the comment's conversation, if it's resolved.
+    pub resolver: Option<User>,
+    pub updated_at: Timestamp,
+    pub user: Option<User>,
+}
+
+/// A line-level comment to post with a new review.
+/// See [CreateReviewBuilder::comment](crate::api::pulls::reviews::create::CreateReviewBuilder::comment).
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReviewComment {
+    pub body: String,
+    /// The line to comment on, in the new version of the file. 0 if the comment is on an old line.
+    pub new_position: i64,
+    /// The line to comment on, in the old version of the file. 0 if the comment is on a new line.
+    pub old_position: i64,
+    /// The path of the file to comment on.
+    pub path: String,
+}
+
+impl CreateReviewComment {
+    /// A comment on a line of the new version of a file, like an added or unchanged line.
+    pub fn new_line(path: impl ToString, line: i64, body: impl ToString) -> Self {
+        Self {
+            body: body.to_string(),
+            new_position: line,
+            old_position: 0,
+            path: path.to_string(),
+        }
+    }
+    /// A comment on a line of the old version of a file, like a removed line.
+    pub fn old_line(path: impl ToString, line: i64, body: impl ToString) -> Self {
+        Self {
+            body: body.to_string(),
+            new_position: 0,
+            old_position: line,
+            path: path.to_string(),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{repos::Repository, user::User, Timestamp};
+
+/// The state of a commit status, as reported by a CI system or other external service.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommitStatusState {
+    /// The check is still running.
+    #[default]
+    Pending,
+    Success,
+    /// The check couldn't run, for example because of a misconfiguration.
+    Error,
+    /// The check ran and failed.
+    Failure,
+    /// The check succeeded with warnings.
+    Warning,
+    /// A state this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Represents the status of a commit, reported by an external service like a CI system.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommitStatus {
+    /// The label that distinguishes this status from those of other services, like
+    /// `ci/build`.
+    pub context: String,
+    pub created_at: Timestamp,
+    /// The user who created the status.
+    pub creator: Option<User>,
+    /// A short description of the status.
+    pub description: String,
+    pub id: i64,
+    pub status: CommitStatusState,
+    /// The URL to the details of the status, like a build log.
+    pub target_url: String,
+    pub updated_at: Timestamp,
+    /// The API endpoint for the status.
+    pub url: String,
+}
+
+/// The combined status of a commit.
+/// Contains the latest status of each context.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CombinedStatus {
+    /// The API endpoint for the commit.
+    pub commit_url: String,
+    pub repository: Option<Repository>,
+    /// The SHA of the commit.
+    pub sha: String,
+    /// The overall state: [Failure](CommitStatusState::Failure) or
+    /// [Error](CommitStatusState::Error) if any status failed, [Pending](CommitStatusState::Pending)
+    /// if any status is still pending, [Success](CommitStatusState::Success) otherwise.
+    pub state: CommitStatusState,
+    /// The latest status of each context.
+    pub statuses: Vec<CommitStatus>,
+    /// The number of contexts.
+    pub total_count: i64,
+    /// The API endpoint for the combined status.
+    pub url: String,
+}
@@ -1,8 +1,6 @@
+use super::orgs::Organization;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
-use serde::{Deserialize, Serialize};
-use super::{
-    orgs::Organization,
-};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Team {
@@ -13,11 +11,13 @@ pub struct Team {
     pub name: String,
     pub organization: Option<Organization>,
     pub permission: Permission,
-    pub units: Vec<String>,
-    pub units_map: HashMap<String, String>,
+    /// The repository units the team has access to.
+    pub units: Vec<RepoUnit>,
+    /// The team's permission for each repository unit.
+    pub units_map: HashMap<RepoUnit, Permission>,
 }
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Permission {
     #[default]
     #[serde(rename = "none")]
@@ -31,3 +31,85 @@ pub enum Permission {
     #[serde(rename = "owner")]
     Owner,
 }
+
+/// A part of a repository that access can be granted to separately, like its code or issues.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RepoUnit {
+    /// `repo.code`
+    Code,
+    /// `repo.issues`
+    Issues,
+    /// `repo.ext_issues`
+    ExternalIssues,
+    /// `repo.wiki`
+    Wiki,
+    /// `repo.ext_wiki`
+    ExternalWiki,
+    /// `repo.pulls`
+    PullRequests,
+    /// `repo.releases`
+    Releases,
+    /// `repo.projects`
+    Projects,
+    /// `repo.packages`
+    Packages,
+    /// `repo.actions`
+    Actions,
+    /// A unit this version of the crate doesn't know about, with its name as Gitea sent it.
+    Other(String),
+}
+
+impl RepoUnit {
+    /// The unit's name in the API, like `repo.code`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            RepoUnit::Code => "repo.code",
+            RepoUnit::Issues => "repo.issues",
+            RepoUnit::ExternalIssues => "repo.ext_issues",
+            RepoUnit::Wiki => "repo.wiki",
+            RepoUnit::ExternalWiki => "repo.ext_wiki",
+            RepoUnit::PullRequests => "repo.pulls",
+            RepoUnit::Releases => "repo.releases",
+            RepoUnit::Projects => "repo.projects",
+            RepoUnit::Packages => "repo.packages",
+            RepoUnit::Actions => "repo.actions",
+            RepoUnit::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for RepoUnit {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "repo.code" => RepoUnit::Code,
+            "repo.issues" => RepoUnit::Issues,
+            "repo.ext_issues" => RepoUnit::ExternalIssues,
+            "repo.wiki" => RepoUnit::Wiki,
+            "repo.ext_wiki" => RepoUnit::ExternalWiki,
+            "repo.pulls" => RepoUnit::PullRequests,
+            "repo.releases" => RepoUnit::Releases,
+            "repo.projects" => RepoUnit::Projects,
+            "repo.packages" => RepoUnit::Packages,
+            "repo.actions" => RepoUnit::Actions,
+            _ => RepoUnit::Other(name),
+        }
+    }
+}
+
+impl From<&str> for RepoUnit {
+    fn from(name: &str) -> Self {
+        RepoUnit::from(name.to_string())
+    }
+}
+
+impl Serialize for RepoUnit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RepoUnit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(RepoUnit::from)
+    }
+}
@@ -0,0 +1,218 @@
+//! Timestamps in models and request parameters.
+//!
+//! A [Timestamp] holds the RFC 3339 string Gitea sends, for example
+//! `2024-05-01T12:30:00+02:00`. Enable the `chrono` feature to convert it into a
+//! `chrono::DateTime<Utc>` with `Timestamp::as_datetime`, and to pass `DateTime` values to
+//! filter parameters like `since`.
+//!
+//! The type and the values of timestamps are the same with and without the feature, so
+//! enabling it anywhere in a dependency graph doesn't break other crates using this one.
+//! Timestamps dereference to `str` and compare equal to strings, so code written for the
+//! plain `String` fields of earlier versions keeps working.
+//!
+//! Gitea uses "zero" dates (`0001-01-01T00:00:00Z` or the Unix epoch) and empty strings for
+//! timestamps that are not set. Optional timestamps like `merged_at` or `due_date` are `None`
+//! in that case, and required timestamps are empty if Gitea sends an empty string or `null`.
+
+use std::fmt::{self, Display};
+use std::ops::Deref;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A point in time. See the [module documentation](self) for details.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Timestamp(String);
+
+impl Timestamp {
+    /// The timestamp as Gitea sent it. Empty if it isn't set.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the timestamp is empty or one of the zero dates Gitea uses for timestamps that
+    /// are not set.
+    pub fn is_zero(&self) -> bool {
+        let value = self.0.trim();
+        value.is_empty()
+            || value.starts_with("0001-")
+            || value.starts_with("0000-")
+            || is_epoch(value)
+    }
+
+    /// Parses the timestamp into a [`DateTime<Utc>`](chrono::DateTime).
+    /// Returns `None` if the timestamp is not set (see [Timestamp::is_zero]) or is not a valid
+    /// date.
+    #[cfg(feature = "chrono")]
+    pub fn as_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+        if self.is_zero() {
+            return None;
+        }
+        let value = self.0.trim();
+        DateTime::parse_from_rfc3339(value)
+            .map(|date| date.with_timezone(&Utc))
+            .or_else(|_| {
+                NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f").map(|d| d.and_utc())
+            })
+            .or_else(|_| {
+                NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f").map(|d| d.and_utc())
+            })
+            .ok()
+            .or_else(|| {
+                NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .map(|date| date.and_utc())
+            })
+    }
+}
+
+/// Whether a timestamp like `1970-01-01T01:00:00+01:00` is the Unix epoch, in any time zone.
+fn is_epoch(value: &str) -> bool {
+    let day = match value.get(..10) {
+        Some("1970-01-01") => 0,
+        Some("1969-12-31") => -86_400,
+        _ => return false,
+    };
+    let Some(time) = value.get(11..) else {
+        return day == 0 && value.len() == 10;
+    };
+    let (clock, offset) = time.split_at(time.find(['Z', '+', '-']).unwrap_or(time.len()));
+    let (clock, fraction) = clock.split_once('.').unwrap_or((clock, ""));
+    let seconds = |value: &str| {
+        value.split(':').try_fold(0, |total, part| {
+            Some(total * 60 + part.parse::<i64>().ok()?)
+        })
+    };
+    let offset = match offset.split_at(offset.len().min(1)) {
+        ("+", offset) => seconds(offset).map(|minutes| minutes * 60),
+        ("-", offset) => seconds(offset).map(|minutes| -minutes * 60),
+        _ => Some(0),
+    };
+    fraction.bytes().all(|b| b == b'0')
+        && seconds(clock)
+            .zip(offset)
+            .map(|(clock, offset)| day + clock - offset)
+            == Some(0)
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Deref for Timestamp {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Timestamp {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Timestamp {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Timestamp {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Timestamp {
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Timestamp> for str {
+    fn eq(&self, other: &Timestamp) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<Timestamp> for &str {
+    fn eq(&self, other: &Timestamp) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialEq<Timestamp> for String {
+    fn eq(&self, other: &Timestamp) -> bool {
+        *self == other.0
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Timestamp {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<Timestamp> for String {
+    fn from(value: Timestamp) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp
+where
+    Tz::Offset: Display,
+{
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        Self(value.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// Accepts `null` as an empty timestamp.
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self(
+            Option::<String>::deserialize(deserializer)?.unwrap_or_default(),
+        ))
+    }
+}
+
+/// (De)serializes an optional [Timestamp], mapping empty strings and zero dates to `None`.
+pub(crate) mod lenient_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Timestamp;
+
+    pub fn serialize<S: Serializer>(
+        timestamp: &Option<Timestamp>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        timestamp.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Timestamp>, D::Error> {
+        let timestamp = Option::<Timestamp>::deserialize(deserializer)?;
+        Ok(timestamp.filter(|timestamp| !timestamp.is_zero()))
+    }
+}
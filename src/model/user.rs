@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::Timestamp;
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 /// Represents a user's settings.
 pub struct UserSettings {
@@ -40,7 +42,7 @@ pub struct User {
     /// URL to the user's avatar.
     pub avatar_url: String,
     /// Date the user was created at.
-    pub created: String,
+    pub created: Timestamp,
     /// Description of the user (empty string if the user did not provide a discription).
     pub description: String,
     /// Email of the user.
@@ -58,7 +60,7 @@ pub struct User {
     /// Language the user speaks (empty string if the user did not specify any languaged).
     pub language: String,
     /// Date the user last logged in.
-    pub last_login: String,
+    pub last_login: Timestamp,
     /// Location of the user (empty string if the user did not provide a location).
     pub location: String,
     /// The user's username
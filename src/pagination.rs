@@ -0,0 +1,256 @@
+//! Automatic pagination for list endpoints.
+//!
+//! Every builder that lists something (issues, pull requests, commits, branches, ...) only
+//! returns a single page when calling `send`. The [Paginated] trait is implemented for all of
+//! those builders and lets you fetch every page without writing the loop yourself.
+//!
+//! # Example
+//! ```
+//! # use gitea_sdk::{Client, Auth, pagination::Paginated};
+//! # use futures_util::StreamExt;
+//! # async fn list_all_issues() {
+//! let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+//! // Collect every issue of the repository into a single Vec.
+//! let issues = client
+//!     .issues("owner", "repo")
+//!     .list()
+//!     .send_all(&client)
+//!     .await
+//!     .unwrap();
+//!
+//! // Or process the commits one by one, fetching pages lazily as you go and stopping after
+//! // 500 commits.
+//! let mut commits = client
+//!     .repos("owner", "repo")
+//!     .get_commits()
+//!     .limit(50)
+//!     .paginate()
+//!     .max_items(500)
+//!     .stream(&client);
+//! while let Some(commit) = commits.next().await {
+//!     println!("{}", commit.unwrap().sha);
+//! }
+//! # }
+//! ```
+use std::collections::VecDeque;
+use std::future::Future;
+
+use futures_core::Stream;
+use futures_util::{stream, TryStreamExt};
+use reqwest::header::LINK;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    error::{Result, TeatimeError, TeatimeErrorKind},
+    response::ApiResponse,
+    Client,
+};
+
+/// Implemented by every builder for an endpoint that returns a paginated list.
+///
+/// The `page` set on the builder (if any) is used as the first page to fetch, the `limit` is
+/// used as the page size for every request.
+pub trait Paginated: Serialize + Clone + Send + Sized + 'static {
+    /// The type of item the endpoint lists.
+    type Item: DeserializeOwned + Send + 'static;
+
+    /// The API path of the endpoint, relative to `/api/v1/`.
+    fn path(&self) -> String;
+    /// The page number set on the builder.
+    fn page(&self) -> Option<i64>;
+    /// The page size set on the builder.
+    fn limit(&self) -> Option<i64>;
+    /// Returns a copy of the builder that requests the given page.
+    fn with_page(&self, page: i64) -> Self;
+
+    /// Extracts the items from the JSON body of a single page.
+    /// Most endpoints return a plain JSON array, the search endpoints wrap it in an object.
+    /// Use [decode] to turn the body into the items, so that bodies that don't match fail with
+    /// the same error as in [Client::parse_response].
+    fn items(body: Value) -> Result<Vec<Self::Item>> {
+        decode(body)
+    }
+
+    /// Sends the request for the builder's page and returns the items together with the
+    /// response's metadata, like the total number of items and the number of the next page.
+    ///
+    /// # Example
+    /// ```
+    /// # use gitea_sdk::{Client, Auth, pagination::Paginated};
+    /// # async fn count_issues() {
+    /// let client = Client::new("https://gitea.example.com", Auth::Token("your-token"));
+    /// let res = client
+    ///     .issues("owner", "repo")
+    ///     .list()
+    ///     .limit(1)
+    ///     .send_with_meta(&client)
+    ///     .await
+    ///     .unwrap();
+    /// println!("The repository has {:?} issues", res.total_count);
+    /// # }
+    /// ```
+    fn send_with_meta<'a>(
+        &self,
+        client: &'a Client,
+    ) -> impl Future<Output = Result<ApiResponse<Vec<Self::Item>>>> + Send + 'a {
+        let builder = self.clone();
+        async move {
+            let req = client.get(builder.path()).query(&builder).build()?;
+            let res = client.make_request(req).await?;
+            let path = res.url().path().to_string();
+            let mut res = client.parse_response_with_meta::<Value>(res).await?;
+            let items = Self::items(res.body.take()).map_err(|e| TeatimeError {
+                status_code: Some(res.status),
+                path: Some(path),
+                ..e
+            })?;
+            Ok(res.map(|_| items))
+        }
+    }
+
+    /// Creates a [Paginator] for this builder, which can be used to limit the number of items
+    /// fetched before streaming or collecting them.
+    fn paginate(&self) -> Paginator<Self> {
+        Paginator::new(self.clone())
+    }
+
+    /// Returns a [Stream] over all items of the endpoint, starting at the builder's page.
+    /// Pages are fetched lazily as the stream is polled.
+    fn stream<'a>(
+        &self,
+        client: &'a Client,
+    ) -> impl Stream<Item = Result<Self::Item>> + Send + Unpin + 'a {
+        self.paginate().stream(client)
+    }
+
+    /// Fetches all items of the endpoint, starting at the builder's page.
+    fn send_all<'a>(
+        &self,
+        client: &'a Client,
+    ) -> impl Future<Output = Result<Vec<Self::Item>>> + Send + 'a {
+        self.paginate().send_all(client)
+    }
+}
+
+/// Fetches every page of a [Paginated] builder.
+///
+/// Gitea's `Link` header is followed to find the next page. If an instance (or a proxy in front
+/// of it) does not send that header, the `X-Total-Count` header, a page that is shorter than the
+/// requested `limit`, or an empty page stop the pagination instead.
+#[derive(Debug, Clone)]
+pub struct Paginator<B: Paginated> {
+    builder: B,
+    max_items: Option<usize>,
+}
+
+struct State<'a, B: Paginated> {
+    builder: B,
+    client: &'a Client,
+    next_page: Option<i64>,
+    buffer: VecDeque<B::Item>,
+    /// Number of items yielded so far.
+    yielded: usize,
+    /// Number of items per page, used to tell how many items come before a page. This is the
+    /// builder's `limit`, or the length of the first page if it has none.
+    page_size: Option<usize>,
+    max_items: Option<usize>,
+}
+
+impl<B: Paginated> Paginator<B> {
+    /// Creates a paginator that starts at the builder's page.
+    /// Usually created with [Paginated::paginate].
+    pub fn new(builder: B) -> Self {
+        Self {
+            builder,
+            max_items: None,
+        }
+    }
+
+    /// Stops fetching after the given number of items have been returned.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Returns a [Stream] over all items of the endpoint.
+    /// Pages are fetched lazily as the stream is polled.
+    pub fn stream(
+        self,
+        client: &Client,
+    ) -> impl Stream<Item = Result<B::Item>> + Send + Unpin + '_ {
+        let state = State {
+            next_page: Some(self.builder.page().unwrap_or(1)),
+            page_size: self.builder.limit().map(|limit| limit as usize),
+            builder: self.builder,
+            client,
+            buffer: VecDeque::new(),
+            yielded: 0,
+            max_items: self.max_items,
+        };
+        Box::pin(stream::try_unfold(state, |mut state| async move {
+            loop {
+                if state.max_items.is_some_and(|max| state.yielded >= max) {
+                    return Ok(None);
+                }
+                if let Some(item) = state.buffer.pop_front() {
+                    state.yielded += 1;
+                    return Ok(Some((item, state)));
+                }
+                let Some(page) = state.next_page else {
+                    return Ok(None);
+                };
+                state.fetch(page).await?;
+            }
+        }))
+    }
+
+    /// Fetches all items of the endpoint.
+    pub async fn send_all(self, client: &Client) -> Result<Vec<B::Item>> {
+        self.stream(client).try_collect().await
+    }
+}
+
+impl<B: Paginated> State<'_, B> {
+    /// Fetches the given page into the buffer and determines the next page to fetch.
+    async fn fetch(&mut self, page: i64) -> Result<()> {
+        let res = self
+            .builder
+            .with_page(page)
+            .send_with_meta(self.client)
+            .await?;
+
+        let len = res.body.len();
+        self.buffer.extend(res.body);
+        self.next_page = if len == 0 {
+            None
+        } else if res.headers.contains_key(LINK) {
+            res.next_page
+        } else if let Some(total) = res.total_count {
+            // The total includes the pages before the one the paginator started at.
+            let page_size = *self.page_size.get_or_insert(len);
+            let before = (page.max(1) - 1) as usize * page_size;
+            (before + len < total).then_some(page + 1)
+        } else if self
+            .builder
+            .limit()
+            .is_some_and(|limit| (len as i64) < limit)
+        {
+            None
+        } else {
+            Some(page + 1)
+        };
+        Ok(())
+    }
+}
+
+/// Deserializes the items of a page (or the object wrapping them) from a response body.
+/// Fails with a [Decode](TeatimeErrorKind::Decode) error like [Client::parse_response].
+pub fn decode<T: DeserializeOwned>(body: Value) -> Result<T> {
+    serde_json::from_value(body).map_err(|e| {
+        TeatimeError::new(
+            TeatimeErrorKind::Decode,
+            format!("Error parsing response: {}", e),
+        )
+    })
+}
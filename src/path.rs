@@ -0,0 +1,57 @@
+//! Percent-encoding of values that are spliced into API paths.
+//!
+//! Every builder that puts a user-supplied value (an owner, a repository, a branch, a file path,
+//! ...) into the request path must encode it with one of these functions, so that characters
+//! like `#`, `?`, `%` or spaces end up in the right place instead of changing the URL's meaning.
+use std::fmt::{self, Display};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, PercentEncode, CONTROLS};
+
+/// ASCII characters that may not appear unencoded in a single path segment. Letters, digits and
+/// `-._~!$&'()*+,;=:@` are kept as is. Non-ASCII characters are always UTF-8 encoded and then
+/// percent-encoded, so `ü` becomes `%C3%BC`.
+const SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// Encodes a value that makes up exactly one path segment, like an owner, a repository name or
+/// a username. A `/` in the value is encoded as `%2F`.
+pub(crate) fn segment(value: &str) -> PercentEncode<'_> {
+    utf8_percent_encode(value, SEGMENT)
+}
+
+/// Encodes a value that may span several path segments, like a file path or a branch name.
+/// Each segment is encoded like [segment], but the `/` between them is kept, because Gitea
+/// matches these parameters with a wildcard route.
+pub(crate) fn segments(value: &str) -> Segments<'_> {
+    Segments(value)
+}
+
+/// A value encoded with [segments].
+pub(crate) struct Segments<'a>(&'a str);
+
+impl Display for Segments<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, part) in self.0.split('/').enumerate() {
+            if i > 0 {
+                f.write_str("/")?;
+            }
+            write!(f, "{}", segment(part))?;
+        }
+        Ok(())
+    }
+}
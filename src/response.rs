@@ -0,0 +1,155 @@
+//! Response metadata returned alongside parsed response bodies.
+//!
+//! List builders return an [ApiResponse] from
+//! [Paginated::send_with_meta](crate::pagination::Paginated::send_with_meta). Builders for
+//! single items don't have a `send_with_meta` variant; to read the metadata of such a request,
+//! build it with the client's request helpers and parse it with
+//! [Client::parse_response_with_meta](crate::Client::parse_response_with_meta).
+//!
+//! # Example
+//! ```
+//! # use gitea_sdk::{Client, Auth, model::repos::Repository};
+//! # async fn get_repo_etag() {
+//! let client = Client::new(
+//!     "https://gitea.example.com",
+//!     Auth::Token("your-token")
+//! );
+//! let req = client.get("repos/owner/repo").build().unwrap();
+//! let res = client.make_request(req).await.unwrap();
+//! let repo = client
+//!     .parse_response_with_meta::<Repository>(res)
+//!     .await
+//!     .unwrap();
+//! println!("{} has the ETag {:?}", repo.body.full_name, repo.etag());
+//! # }
+//! ```
+use reqwest::header::{HeaderMap, ETAG, LINK};
+use reqwest::{StatusCode, Url};
+
+/// The header Gitea uses to report the total number of items of a list endpoint.
+pub const TOTAL_COUNT_HEADER: &str = "x-total-count";
+/// The header Gitea uses to report whether there are more items than returned.
+/// Only sent by endpoints that can't cheaply count their items.
+pub const HAS_MORE_HEADER: &str = "x-hasmore";
+
+/// A parsed response body together with the response's metadata.
+///
+/// Use this if you need more than just the body of a response, for example to show the total
+/// number of items of a list without fetching all pages. See the [module docs](self) for how to
+/// get one.
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// The parsed response body.
+    pub body: T,
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+    /// Total number of items of a list endpoint, taken from the `X-Total-Count` header.
+    pub total_count: Option<usize>,
+    /// Number of the first page, taken from the `Link` header.
+    pub first_page: Option<i64>,
+    /// Number of the previous page, taken from the `Link` header.
+    pub prev_page: Option<i64>,
+    /// Number of the next page, taken from the `Link` header.
+    /// This is `None` if the response is the last page.
+    pub next_page: Option<i64>,
+    /// Number of the last page, taken from the `Link` header.
+    pub last_page: Option<i64>,
+    /// All headers of the response.
+    pub headers: HeaderMap,
+}
+
+impl<T> ApiResponse<T> {
+    /// Creates a new [ApiResponse] by reading the metadata out of the given headers.
+    pub fn new(body: T, status: StatusCode, headers: HeaderMap) -> Self {
+        Self {
+            body,
+            status,
+            total_count: total_count(&headers),
+            first_page: link_page(&headers, "first"),
+            prev_page: link_page(&headers, "prev"),
+            next_page: link_page(&headers, "next"),
+            last_page: link_page(&headers, "last"),
+            headers,
+        }
+    }
+
+    /// Whether there are more items after this page.
+    /// This uses the `X-HasMore` header if the endpoint sends it and falls back to the `Link`
+    /// header otherwise.
+    pub fn has_more(&self) -> bool {
+        match self.header(HAS_MORE_HEADER) {
+            Some(value) => value.eq_ignore_ascii_case("true"),
+            None => self.next_page.is_some(),
+        }
+    }
+
+    /// The `ETag` of the response, if any.
+    pub fn etag(&self) -> Option<&str> {
+        self.header(ETAG.as_str())
+    }
+
+    /// Returns the value of the given header, if it is present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    /// Maps the body of the response, keeping the metadata.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ApiResponse<U> {
+        ApiResponse {
+            body: f(self.body),
+            status: self.status,
+            total_count: self.total_count,
+            first_page: self.first_page,
+            prev_page: self.prev_page,
+            next_page: self.next_page,
+            last_page: self.last_page,
+            headers: self.headers,
+        }
+    }
+
+    /// Discards the metadata and returns the body.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
+
+/// Parses the `X-Total-Count` header of a response.
+fn total_count(headers: &HeaderMap) -> Option<usize> {
+    headers
+        .get(TOTAL_COUNT_HEADER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Parses the page number of the link with the given relation (`next`, `prev`, `first` or
+/// `last`) out of the `Link` header of a response.
+fn link_page(headers: &HeaderMap, rel: &str) -> Option<i64> {
+    headers
+        .get_all(LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|link| {
+            let mut parts = link.split(';');
+            let url = parts.next()?.trim().strip_prefix('<')?.strip_suffix('>')?;
+            let matches = parts.any(|param| {
+                param
+                    .trim()
+                    .strip_prefix("rel=")
+                    .is_some_and(|value| value.trim_matches('"') == rel)
+            });
+            if !matches {
+                return None;
+            }
+            Url::parse(url)
+                .ok()?
+                .query_pairs()
+                .find(|(key, _)| key == "page")?
+                .1
+                .parse()
+                .ok()
+        })
+}
@@ -0,0 +1,168 @@
+//! Retrying failed requests.
+//!
+//! By default, a [Client](crate::Client) sends every request exactly once. Set a [RetryPolicy]
+//! on the client to retry requests that failed because of transient errors, like a `502 Bad
+//! Gateway` from a reverse proxy in front of Gitea.
+//!
+//! # Example
+//! ```
+//! # use std::time::{Duration, SystemTime};
+//! # use gitea_sdk::{Client, Auth, retry::RetryPolicy};
+//! let client = Client::new("https://gitea.example.com", Auth::Token("your-token"))
+//!     .with_retry_policy(
+//!         RetryPolicy::new()
+//!             .max_attempts(5)
+//!             .initial_backoff(Duration::from_millis(200)),
+//!     );
+//! ```
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+
+/// Describes if and how failed requests are retried.
+///
+/// A request is retried if its method is one of the retryable methods and it either failed to
+/// connect, timed out, or returned one of the retryable status codes. Between attempts, the
+/// client waits for an exponentially growing backoff (with random jitter), or for the duration
+/// the server asked for in its `Retry-After` header.
+///
+/// By default, only idempotent methods (`GET`, `HEAD`, `OPTIONS`, `PUT` and `DELETE`) are
+/// retried, so a request that creates something is never sent twice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    respect_retry_after: bool,
+    statuses: Vec<StatusCode>,
+    methods: Vec<Method>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            respect_retry_after: true,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            methods: vec![
+                Method::GET,
+                Method::HEAD,
+                Method::OPTIONS,
+                Method::PUT,
+                Method::DELETE,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates the default retry policy: up to 3 attempts for idempotent requests that failed
+    /// with `429`, `502`, `503` or `504`, starting with a backoff of 500ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A policy that never retries. This is what a [Client](crate::Client) uses by default.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Total number of attempts, including the first one. `1` disables retrying.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Backoff before the first retry. Doubles with every following retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Upper bound for the backoff between two attempts, including any `Retry-After` delay.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Whether to randomize the backoff, so that many clients failing at the same time don't
+    /// retry at the same time. Enabled by default.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Whether to wait for the duration given in a response's `Retry-After` header instead of
+    /// the computed backoff. Both the delay in seconds and the HTTP-date form of the header are
+    /// supported. Enabled by default.
+    pub fn respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Status codes that cause a request to be retried.
+    pub fn retry_statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Methods that may be retried. Only add non-idempotent methods like `POST` or `PATCH` if
+    /// sending the same request twice is safe for the endpoints you use.
+    pub fn retry_methods(mut self, methods: impl IntoIterator<Item = Method>) -> Self {
+        self.methods = methods.into_iter().collect();
+        self
+    }
+
+    /// Whether a request with the given method may be attempted again after `attempt` attempts.
+    pub(crate) fn can_retry(&self, method: &Method, attempt: u32) -> bool {
+        attempt < self.max_attempts && self.methods.contains(method)
+    }
+
+    /// Whether a response with the given status should be retried.
+    pub(crate) fn retries_status(&self, status: StatusCode) -> bool {
+        self.statuses.contains(&status)
+    }
+
+    /// The delay before the next attempt, after `attempt` attempts have failed.
+    pub(crate) fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        let retry_after = headers
+            .filter(|_| self.respect_retry_after)
+            .and_then(retry_after);
+        let delay = retry_after.unwrap_or_else(|| {
+            let exponent = attempt.saturating_sub(1).min(31);
+            let backoff = self.initial_backoff.saturating_mul(1 << exponent);
+            if self.jitter {
+                // "Equal jitter": wait at least half of the backoff, plus a random share of the
+                // other half.
+                backoff / 2 + backoff.mul_f64(fastrand::f64() / 2.0)
+            } else {
+                backoff
+            }
+        });
+        delay.min(self.max_backoff)
+    }
+}
+
+/// Parses the `Retry-After` header, which is either a number of seconds or an HTTP-date.
+/// A date in the past results in no delay.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
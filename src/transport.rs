@@ -0,0 +1,81 @@
+//! Sending HTTP requests.
+//!
+//! A [Client](crate::Client) builds its requests with reqwest, but hands them to a [Transport]
+//! to actually send them. By default, that's a [ReqwestTransport] configured by the
+//! [ClientBuilder](crate::ClientBuilder). Use [ClientBuilder::transport](crate::ClientBuilder::transport)
+//! to plug in your own transport, for example to record requests, to route them through
+//! another HTTP stack, or to answer them in-process in tests.
+//!
+//! Requests passed to a transport already contain the client's default headers (like
+//! `Authorization` and `User-Agent`). Retrying is handled by the client, so a transport should
+//! send each request exactly once.
+//!
+//! # Example
+//! A fake transport that answers every request with an empty list. `http` is the
+//! [http crate](https://docs.rs/http); reqwest converts its responses into [reqwest::Response]s.
+//! ```
+//! # use gitea_sdk::{Client, Auth};
+//! # async fn fake_transport() {
+//! let client = Client::builder("https://gitea.example.com")
+//!     .auth(Auth::Token("your-token"))
+//!     .transport(|request: reqwest::Request| async move {
+//!         assert_eq!(request.url().path(), "/api/v1/repos/owner/repo/branches");
+//!         Ok(http::Response::new("[]").into())
+//!     })
+//!     .build()
+//!     .unwrap();
+//! let branches = client
+//!     .repos("owner", "repo")
+//!     .list_branches()
+//!     .send(&client)
+//!     .await
+//!     .unwrap();
+//! assert!(branches.is_empty());
+//! # }
+//! ```
+use std::future::Future;
+
+use futures_core::future::BoxFuture;
+use reqwest::{Request, Response};
+
+use crate::error::Result;
+
+/// Sends a request and returns its response. See the [module documentation](self).
+///
+/// Error statuses (like `404`) are not errors at this level: return the response, and the
+/// client turns it into a [TeatimeError](crate::error::TeatimeError). Only return an error if
+/// no response was received. Errors of kind [Connect](crate::error::TeatimeErrorKind::Connect)
+/// and [Timeout](crate::error::TeatimeErrorKind::Timeout) may be retried by the client.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>>;
+}
+
+/// Any function from a request to a future of a response is a transport.
+impl<F, Fut> Transport for F
+where
+    F: Fn(Request) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Response>> + Send + 'static,
+{
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(self(request))
+    }
+}
+
+/// The default [Transport], which sends requests with a [reqwest::Client].
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport that sends requests with the given client.
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(async move { Ok(self.client.execute(request).await?) })
+    }
+}
@@ -0,0 +1,239 @@
+//! Payloads of the webhook events Gitea delivers.
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    issues::{Comment, Issue},
+    pulls::PullRequest,
+    releases::Release,
+    repos::{PayloadCommit, Repository},
+    user::User,
+};
+
+/// What happened to an issue or pull request.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueAction {
+    #[default]
+    Opened,
+    Closed,
+    Reopened,
+    Edited,
+    Deleted,
+    Assigned,
+    Unassigned,
+    LabelUpdated,
+    LabelCleared,
+    /// New commits were pushed to a pull request.
+    Synchronized,
+    Milestoned,
+    Demilestoned,
+    Reviewed,
+    ReviewRequested,
+    ReviewRequestRemoved,
+    /// An action this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// What happened to a comment.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentAction {
+    #[default]
+    Created,
+    Edited,
+    Deleted,
+    /// An action this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// What happened to a release.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseAction {
+    #[default]
+    Published,
+    Updated,
+    Deleted,
+    /// An action this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// What happened to a repository.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepositoryAction {
+    #[default]
+    Created,
+    Deleted,
+    /// An action this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The kind of ref that was created or deleted.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefType {
+    #[default]
+    Branch,
+    Tag,
+    /// A ref type this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The previous value of an edited field.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChangesFrom {
+    pub from: String,
+}
+
+/// The fields that were changed by an `edited` action.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Changes {
+    pub title: Option<ChangesFrom>,
+    pub body: Option<ChangesFrom>,
+    pub r#ref: Option<ChangesFrom>,
+}
+
+/// The review that triggered a pull request review event.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewPayload {
+    pub r#type: String,
+    pub content: String,
+}
+
+/// Commits were pushed to a branch, or a tag was pushed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PushEvent {
+    /// The full name of the ref that was pushed to, like `refs/heads/main`.
+    pub r#ref: String,
+    /// The SHA of the ref before the push. All zeros if the ref was created.
+    pub before: String,
+    /// The SHA of the ref after the push. All zeros if the ref was deleted.
+    pub after: String,
+    /// A URL comparing `before` and `after`.
+    pub compare_url: String,
+    /// The pushed commits, newest last.
+    /// Gitea limits the number of commits in a payload, see `total_commits`.
+    pub commits: Vec<PayloadCommit>,
+    pub total_commits: i64,
+    pub head_commit: Option<PayloadCommit>,
+    pub repository: Repository,
+    pub pusher: User,
+    pub sender: User,
+}
+
+/// A branch or tag was created.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreateEvent {
+    /// The SHA the ref points to.
+    pub sha: String,
+    /// The name of the ref, like `main` or `v1.0.0`.
+    pub r#ref: String,
+    pub ref_type: RefType,
+    pub repository: Repository,
+    pub sender: User,
+}
+
+/// A branch or tag was deleted.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeleteEvent {
+    /// The name of the ref, like `feature` or `v1.0.0`.
+    pub r#ref: String,
+    pub ref_type: RefType,
+    pub pusher_type: String,
+    pub repository: Repository,
+    pub sender: User,
+}
+
+/// A repository was forked.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForkEvent {
+    /// The new fork.
+    pub forkee: Repository,
+    /// The repository that was forked.
+    pub repository: Repository,
+    pub sender: User,
+}
+
+/// An issue was opened, edited, closed, labeled, etc.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IssuesEvent {
+    pub action: IssueAction,
+    pub number: i64,
+    pub changes: Option<Changes>,
+    pub issue: Issue,
+    pub repository: Repository,
+    pub sender: User,
+    /// The SHA of the commit that closed the issue, if it was closed by a commit.
+    pub commit_id: String,
+}
+
+/// A comment on an issue or pull request was created, edited or deleted.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IssueCommentEvent {
+    pub action: CommentAction,
+    pub issue: Issue,
+    /// The pull request, if the comment was made on one.
+    pub pull_request: Option<PullRequest>,
+    pub comment: Comment,
+    pub changes: Option<Changes>,
+    pub repository: Repository,
+    pub sender: User,
+    /// Whether the comment was made on a pull request.
+    pub is_pull: bool,
+}
+
+/// A pull request was opened, edited, closed, synchronized, reviewed, etc.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestEvent {
+    pub action: IssueAction,
+    pub number: i64,
+    #[serde(default)]
+    pub changes: Option<Changes>,
+    pub pull_request: PullRequest,
+    /// The reviewer that was requested or removed, for review request actions.
+    #[serde(default)]
+    pub requested_reviewer: Option<User>,
+    pub repository: Repository,
+    pub sender: User,
+    /// The SHA of the merge commit, if the pull request was merged.
+    #[serde(default)]
+    pub commit_id: String,
+    /// The review, for review events.
+    #[serde(default)]
+    pub review: Option<ReviewPayload>,
+}
+
+/// A release was published, updated or deleted.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReleaseEvent {
+    pub action: ReleaseAction,
+    pub release: Release,
+    pub repository: Repository,
+    pub sender: User,
+}
+
+/// A repository was created or deleted.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepositoryEvent {
+    pub action: RepositoryAction,
+    pub repository: Repository,
+    pub organization: User,
+    pub sender: User,
+}
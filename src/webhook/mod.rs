@@ -0,0 +1,140 @@
+//! Receiving webhooks sent by Gitea.
+//!
+//! Gitea sends the type of an event in the [EVENT_HEADER] and, if the webhook has a secret, an
+//! HMAC-SHA256 signature of the body in the [SIGNATURE_HEADER]. Verify the signature with
+//! [verify_signature] before trusting the payload, then parse it with [Event::parse].
+//!
+//! # Example
+//! ```
+//! use gitea_sdk::webhook::{self, Event};
+//!
+//! fn handle_webhook(event: &str, signature: &str, body: &[u8]) -> Result<(), String> {
+//!     if !webhook::verify_signature("webhook-secret", body, signature) {
+//!         return Err("invalid signature".to_string());
+//!     }
+//!     match Event::parse(event, body).map_err(|e| e.to_string())? {
+//!         Event::Push(push) => {
+//!             println!("{} pushed to {}", push.pusher.login, push.r#ref);
+//!         }
+//!         Event::PullRequest(pr) => {
+//!             println!("Pull request #{} was {:?}", pr.number, pr.action);
+//!         }
+//!         _ => {}
+//!     }
+//!     Ok(())
+//! }
+//! ```
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::error::{Result, TeatimeError, TeatimeErrorKind};
+
+pub mod events;
+
+pub use events::*;
+
+/// The header containing the type of the event, like `push` or `pull_request`.
+pub const EVENT_HEADER: &str = "x-gitea-event";
+/// The header containing the hex-encoded HMAC-SHA256 signature of the body.
+pub const SIGNATURE_HEADER: &str = "x-gitea-signature";
+/// The header containing the unique ID of the delivery.
+pub const DELIVERY_HEADER: &str = "x-gitea-delivery";
+
+/// A webhook event, parsed according to the [EVENT_HEADER].
+/// The payloads are boxed, because some of them are quite large.
+#[derive(Debug, Clone)]
+pub enum Event {
+    Push(Box<PushEvent>),
+    Create(Box<CreateEvent>),
+    Delete(Box<DeleteEvent>),
+    Fork(Box<ForkEvent>),
+    Issues(Box<IssuesEvent>),
+    IssueComment(Box<IssueCommentEvent>),
+    /// A pull request event. This includes reviews (`pull_request_approved`,
+    /// `pull_request_rejected` and `pull_request_comment`), see [PullRequestEvent::review].
+    PullRequest(Box<PullRequestEvent>),
+    Release(Box<ReleaseEvent>),
+    Repository(Box<RepositoryEvent>),
+    /// An event without a typed payload, like `wiki` or `package`.
+    Unknown {
+        /// The value of the [EVENT_HEADER].
+        event: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl Event {
+    /// Parses the body of a webhook delivery.
+    /// `event` is the value of the [EVENT_HEADER].
+    pub fn parse(event: &str, body: &[u8]) -> Result<Self> {
+        let decode_error = |e: serde_json::Error| {
+            TeatimeError::new(
+                TeatimeErrorKind::Decode,
+                format!("Error parsing {event} payload: {e}"),
+            )
+        };
+        Ok(match event {
+            "push" => Event::Push(serde_json::from_slice(body).map_err(decode_error)?),
+            "create" => Event::Create(serde_json::from_slice(body).map_err(decode_error)?),
+            "delete" => Event::Delete(serde_json::from_slice(body).map_err(decode_error)?),
+            "fork" => Event::Fork(serde_json::from_slice(body).map_err(decode_error)?),
+            "issues" => Event::Issues(serde_json::from_slice(body).map_err(decode_error)?),
+            "issue_comment" => {
+                Event::IssueComment(serde_json::from_slice(body).map_err(decode_error)?)
+            }
+            "pull_request"
+            | "pull_request_approved"
+            | "pull_request_rejected"
+            | "pull_request_comment" => {
+                Event::PullRequest(serde_json::from_slice(body).map_err(decode_error)?)
+            }
+            "release" => Event::Release(serde_json::from_slice(body).map_err(decode_error)?),
+            "repository" => Event::Repository(serde_json::from_slice(body).map_err(decode_error)?),
+            _ => Event::Unknown {
+                event: event.to_string(),
+                payload: serde_json::from_slice(body).map_err(decode_error)?,
+            },
+        })
+    }
+}
+
+/// Computes the hex-encoded HMAC-SHA256 signature Gitea sends for a body.
+pub fn signature(secret: impl AsRef<[u8]>, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_ref()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Verifies the signature of a webhook delivery.
+/// `signature` is the value of the [SIGNATURE_HEADER]. A `sha256=` prefix (as sent in the
+/// `X-Hub-Signature-256` header) is accepted as well.
+///
+/// The signature is compared in constant time, so an attacker can't learn anything about the
+/// expected signature from how long the comparison takes.
+pub fn verify_signature(secret: impl AsRef<[u8]>, body: &[u8], signature: &str) -> bool {
+    let signature = signature.trim();
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+    let Some(signature) = decode_hex(signature) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_ref()) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&signature).is_ok()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use common::MockServer;
+use gitea_sdk::{error::TeatimeErrorKind, Auth, Client};
+
+async fn request_target(base_url: &str, server: &MockServer) -> String {
+    let client = Client::new(base_url, Auth::Token("token"));
+    client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    server.requests().pop().unwrap().target
+}
+
+#[tokio::test]
+async fn test_base_url_normalization() {
+    let server = MockServer::start(vec![]).await;
+    let url = &server.url;
+    for base_url in [
+        url.clone(),
+        format!("{url}/"),
+        format!("{url}//"),
+        format!("{url}/api/v1"),
+        format!("{url}/api/v1/"),
+    ] {
+        assert_eq!(
+            request_target(&base_url, &server).await,
+            "/api/v1/repos/owner/repo",
+            "base URL {base_url}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_sub_path_install() {
+    let server = MockServer::start(vec![]).await;
+    let url = &server.url;
+    for base_url in [
+        format!("{url}/gitea"),
+        format!("{url}/gitea/"),
+        format!("{url}/gitea/api/v1/"),
+    ] {
+        assert_eq!(
+            request_target(&base_url, &server).await,
+            "/gitea/api/v1/repos/owner/repo",
+            "base URL {base_url}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_paths_are_encoded() {
+    let server = MockServer::start(vec![]).await;
+    let client = Client::new(format!("{}/gitea/", server.url), Auth::Token("token"));
+    // The mock server's empty responses don't decode into the models, only the URL matters.
+    let _ = client
+        .repos("owner", "repo")
+        .contents()
+        .get("docs/my file.md")
+        .send(&client)
+        .await;
+    let _ = client
+        .repos("owner", "repo")
+        .get_branch("feature/x")
+        .send(&client)
+        .await;
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/gitea/api/v1/repos/owner/repo/contents/docs/my%20file.md"
+    );
+    assert_eq!(
+        requests[1].target,
+        "/gitea/api/v1/repos/owner/repo/branches/feature/x"
+    );
+}
+
+#[test]
+fn test_api_url() {
+    let client = Client::new("https://example.com/gitea/api/v1", Auth::None::<String>);
+    assert_eq!(
+        client.api_url("/user/repos?page=2").as_str(),
+        "https://example.com/gitea/api/v1/user/repos?page=2"
+    );
+}
+
+#[test]
+fn test_invalid_base_url() {
+    for base_url in ["gitea.example.com", "ftp://gitea.example.com", ""] {
+        let err = Client::builder(base_url).build().err().unwrap();
+        assert_eq!(err.kind, TeatimeErrorKind::InvalidConfig, "{base_url}");
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "blocking")]
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{blocking, error::TeatimeErrorKind, pagination::Paginated, Auth, Client};
+
+#[test]
+fn test_blocking_send() {
+    let server = MockServer::start_in_background(vec![
+        MockResponse::new(200, r#"{"name": "repo", "stars_count": 3}"#),
+        MockResponse::new(404, r#"{"message": "not found"}"#),
+    ]);
+    let client = blocking::Client::new(&server.url, Auth::Token("token"));
+
+    let repo = client.repos("owner", "repo").get().send(&client).unwrap();
+    assert_eq!(repo.name, "repo");
+    assert_eq!(repo.stars_count, 3);
+
+    let err = client
+        .repos("owner", "missing")
+        .get()
+        .send(&client)
+        .unwrap_err();
+    assert_eq!(err.kind, TeatimeErrorKind::NotFound);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo");
+    assert_eq!(requests[0].header("authorization"), Some("token token"));
+    assert_eq!(requests[1].target, "/api/v1/repos/owner/missing");
+}
+
+#[test]
+fn test_blocking_builder() {
+    let server =
+        MockServer::start_in_background(vec![MockResponse::new(200, r#"[{"name": "fork"}]"#)]);
+    let client = Client::builder(&server.url)
+        .user_agent("my-script")
+        .build_blocking()
+        .unwrap();
+
+    let forks = client
+        .repos("owner", "repo")
+        .get_forks()
+        .send(&client)
+        .unwrap();
+    assert_eq!(forks.len(), 1);
+    assert_eq!(server.requests()[0].header("user-agent"), Some("my-script"));
+}
+
+#[test]
+fn test_block_on() {
+    let server = MockServer::start_in_background(vec![
+        MockResponse::new(200, r#"[{"name": "fork"}]"#),
+        MockResponse::new(200, "[]"),
+    ]);
+    let client = blocking::Client::new(&server.url, Auth::Token("token"));
+
+    let forks = client
+        .block_on(client.repos("owner", "repo").get_forks().send_all(&client))
+        .unwrap();
+    assert_eq!(forks.len(), 1);
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{Auth, Client};
+
+const RULE: &str = r#"{
+    "rule_name": "release/*",
+    "priority": 1,
+    "enable_push": true,
+    "enable_push_whitelist": true,
+    "push_whitelist_usernames": ["release-bot"],
+    "push_whitelist_teams": ["maintainers"],
+    "enable_merge_whitelist": false,
+    "merge_whitelist_usernames": null,
+    "enable_status_check": true,
+    "status_check_contexts": ["ci/build"],
+    "required_approvals": 2,
+    "dismiss_stale_approvals": true,
+    "block_on_outdated_branch": true,
+    "protected_file_patterns": "*.lock",
+    "created_at": "2024-01-01T00:00:00Z",
+    "updated_at": "2024-01-01T00:00:00Z"
+}"#;
+
+#[tokio::test]
+async fn test_branch_protections() {
+    let server = MockServer::start(vec![
+        MockResponse::new(201, RULE),
+        MockResponse::new(200, format!("[{RULE}]")),
+        MockResponse::new(200, RULE),
+        MockResponse::new(200, RULE),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let rules = client.repos("owner", "repo").branch_protections();
+
+    let rule = rules
+        .create("release/*")
+        .enable_push(true)
+        .enable_push_whitelist(true)
+        .push_whitelist_usernames(vec!["release-bot".to_string()])
+        .push_whitelist_teams(vec!["maintainers".to_string()])
+        .enable_merge_whitelist(true)
+        .merge_whitelist_teams(vec!["maintainers".to_string()])
+        .required_approvals(2)
+        .enable_status_check(true)
+        .status_check_contexts(vec!["ci/build".to_string()])
+        .dismiss_stale_approvals(true)
+        .block_on_outdated_branch(true)
+        .protected_file_patterns("*.lock")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(rule.rule_name, "release/*");
+    assert_eq!(rule.required_approvals, 2);
+    assert_eq!(
+        rule.push_whitelist_usernames,
+        Some(vec!["release-bot".to_string()])
+    );
+    assert!(rule.merge_whitelist_usernames.is_none());
+    assert!(rule.block_on_outdated_branch);
+
+    let list = rules.list().send(&client).await.unwrap();
+    assert_eq!(
+        list[0].status_check_contexts,
+        Some(vec!["ci/build".to_string()])
+    );
+    rules.get("release/*").send(&client).await.unwrap();
+    rules
+        .edit("release/*")
+        .required_approvals(1)
+        .dismiss_stale_approvals(false)
+        .send(&client)
+        .await
+        .unwrap();
+    rules.delete("release/*").send(&client).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(
+        requests[0].target,
+        "/api/v1/repos/owner/repo/branch_protections"
+    );
+    assert_eq!(
+        requests[0].body,
+        br#"{"rule_name":"release/*","enable_push":true,"enable_push_whitelist":true,"push_whitelist_usernames":["release-bot"],"push_whitelist_teams":["maintainers"],"enable_merge_whitelist":true,"merge_whitelist_teams":["maintainers"],"enable_status_check":true,"status_check_contexts":["ci/build"],"required_approvals":2,"block_on_outdated_branch":true,"dismiss_stale_approvals":true,"protected_file_patterns":"*.lock"}"#
+    );
+    assert_eq!(
+        requests[2].target,
+        "/api/v1/repos/owner/repo/branch_protections/release%2F*"
+    );
+    assert_eq!(requests[3].method, "PATCH");
+    assert_eq!(
+        requests[3].body,
+        br#"{"required_approvals":1,"dismiss_stale_approvals":false}"#
+    );
+    assert_eq!(requests[4].method, "DELETE");
+}
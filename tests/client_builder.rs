@@ -0,0 +1,161 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{error::TeatimeErrorKind, Auth, Client};
+
+#[tokio::test]
+async fn test_default_headers() {
+    let server = MockServer::start(vec![]).await;
+    let client = Client::builder(&server.url)
+        .auth(Auth::Token("secret"))
+        .default_header("X-Request-Source", "ci")
+        .user_agent("my-bot/1.0")
+        .build()
+        .unwrap();
+    client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("authorization"), Some("token secret"));
+    assert_eq!(request.header("x-request-source"), Some("ci"));
+    assert_eq!(request.header("user-agent"), Some("my-bot/1.0"));
+    assert_eq!(request.header("accept"), Some("application/json"));
+}
+
+#[tokio::test]
+async fn test_default_headers_on_request_helpers() {
+    let server = MockServer::start(vec![]).await;
+    let client = Client::builder(&server.url)
+        .auth(Auth::Token("secret"))
+        .default_header("X-Request-Source", "ci")
+        .user_agent("my-bot/1.0")
+        .build()
+        .unwrap();
+    // Requests sent straight from the request helpers bypass `make_request`.
+    client.get("version").send().await.unwrap();
+    client
+        .get("version")
+        .header("accept", "text/plain")
+        .send()
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/version");
+    assert_eq!(requests[0].header("authorization"), Some("token secret"));
+    assert_eq!(requests[0].header("x-request-source"), Some("ci"));
+    assert_eq!(requests[0].header("user-agent"), Some("my-bot/1.0"));
+    assert_eq!(requests[0].header("accept"), Some("application/json"));
+    // Headers set on the request replace the defaults instead of being sent twice.
+    let accept: Vec<_> = requests[1]
+        .headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("accept"))
+        .collect();
+    assert_eq!(accept.len(), 1);
+    assert_eq!(requests[1].header("accept"), Some("text/plain"));
+}
+
+#[tokio::test]
+async fn test_default_user_agent() {
+    let server = MockServer::start(vec![]).await;
+    let client = Client::new(&server.url, Auth::None::<String>);
+    client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    let expected = format!("gitea-sdk/{}", env!("CARGO_PKG_VERSION"));
+    assert_eq!(request.header("user-agent"), Some(expected.as_str()));
+    assert_eq!(request.header("authorization"), None);
+}
+
+#[tokio::test]
+async fn test_timeout() {
+    // Accept connections, but never answer.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut connections = Vec::new();
+        while let Ok((stream, _)) = listener.accept().await {
+            connections.push(stream);
+        }
+    });
+
+    let client = Client::builder(&url)
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let err = client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind, TeatimeErrorKind::Timeout);
+}
+
+#[tokio::test]
+async fn test_proxy() {
+    // The mock server acts as the proxy, so it receives the absolute URL as request target.
+    let proxy = MockServer::start(vec![MockResponse::new(200, r#"{"name": "repo"}"#)]).await;
+    let client = Client::builder("http://gitea.invalid")
+        .proxy(&proxy.url)
+        .build()
+        .unwrap();
+    let repo = client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(repo.name, "repo");
+    assert_eq!(
+        proxy.requests()[0].target,
+        "http://gitea.invalid/api/v1/repos/owner/repo"
+    );
+}
+
+#[test]
+fn test_invalid_config() {
+    let err = Client::builder("https://gitea.example.com")
+        .default_header("X-Bad\n", "value")
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind, TeatimeErrorKind::InvalidConfig);
+
+    let err = Client::builder("https://gitea.example.com")
+        .auth(Auth::Token("bad\ntoken"))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind, TeatimeErrorKind::InvalidConfig);
+
+    let err = Client::builder("https://gitea.example.com")
+        .proxy("not a url")
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind, TeatimeErrorKind::InvalidConfig);
+}
+
+#[test]
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn test_invalid_root_certificate() {
+    let err = Client::builder("https://gitea.example.com")
+        .add_root_certificate("not a certificate")
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind, TeatimeErrorKind::InvalidConfig);
+}
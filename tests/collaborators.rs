@@ -0,0 +1,87 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{model::team::Permission, Auth, Client};
+
+#[tokio::test]
+async fn test_collaborators() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, r#"[{"id": 1, "login": "alice"}]"#),
+        MockResponse::new(204, ""),
+        MockResponse::new(404, ""),
+        MockResponse::new(204, ""),
+        MockResponse::new(
+            200,
+            r#"{"permission": "write", "role_name": "write", "user": {"id": 1, "login": "alice"}}"#,
+        ),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let collaborators = client.repos("owner", "repo").collaborators();
+
+    let list = collaborators.list().send(&client).await.unwrap();
+    assert_eq!(list[0].login, "alice");
+    assert!(collaborators
+        .is_collaborator("alice")
+        .send(&client)
+        .await
+        .unwrap());
+    assert!(!collaborators
+        .is_collaborator("bob")
+        .send(&client)
+        .await
+        .unwrap());
+    collaborators
+        .add("alice")
+        .permission(Permission::Write)
+        .send(&client)
+        .await
+        .unwrap();
+    let permission = collaborators
+        .permission("alice")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(permission.permission, Permission::Write);
+    assert_eq!(permission.user.login, "alice");
+    collaborators.remove("alice").send(&client).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/collaborators");
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/collaborators/alice"
+    );
+    assert_eq!(requests[3].method, "PUT");
+    assert_eq!(requests[3].body, br#"{"permission":"write"}"#);
+    assert_eq!(
+        requests[4].target,
+        "/api/v1/repos/owner/repo/collaborators/alice/permission"
+    );
+    assert_eq!(requests[5].method, "DELETE");
+    assert_eq!(
+        requests[5].target,
+        "/api/v1/repos/owner/repo/collaborators/alice"
+    );
+}
+
+#[tokio::test]
+async fn test_assignees_and_reviewers() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, r#"[{"login": "alice"}, {"login": "bob"}]"#),
+        MockResponse::new(200, r#"[{"login": "bob"}]"#),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let repo = client.repos("owner", "repo");
+
+    let assignees = repo.list_assignees().send(&client).await.unwrap();
+    assert_eq!(assignees.len(), 2);
+    let reviewers = repo.list_reviewers().send(&client).await.unwrap();
+    assert_eq!(reviewers[0].login, "bob");
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/assignees");
+    assert_eq!(requests[1].target, "/api/v1/repos/owner/repo/reviewers");
+}
@@ -0,0 +1,155 @@
+use gitea_sdk::model::diff::{Diff, LineKind};
+
+const DIFF: &str = r#"diff --git a/src/lib.rs b/src/lib.rs
+index 3b18e51..a2c4f7d 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,4 +1,5 @@ mod api;
+ mod api;
+-mod old;
++mod new;
++mod other;
+ 
+ pub use api::*;
+@@ -20 +21 @@ fn main() {
+-    old();
+\ No newline at end of file
++    new();
+\ No newline at end of file
+diff --git a/docs/new file.md b/docs/new file.md
+new file mode 100644
+index 0000000..e69de29
+--- /dev/null
++++ b/docs/new file.md
+@@ -0,0 +1 @@
++Hello
+diff --git a/gone.txt b/gone.txt
+deleted file mode 100644
+index e69de29..0000000
+--- a/gone.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-Bye
+diff --git a/old.rs b/new.rs
+similarity index 100%
+rename from old.rs
+rename to new.rs
+diff --git a/logo.png b/logo.png
+index 1234567..89abcde 100644
+Binary files a/logo.png and b/logo.png differ
+diff --git "a/caf\303\251.txt" "b/caf\303\251.txt"
+index 1234567..89abcde 100644
+--- "a/caf\303\251.txt"
++++ "b/caf\303\251.txt"
+@@ -1 +1 @@
+-tea
++coffee
+"#;
+
+#[test]
+fn test_parse_diff() {
+    let diff = Diff::parse(DIFF);
+    assert_eq!(diff.files.len(), 6);
+
+    let lib = &diff.files[0];
+    assert_eq!(lib.old_path.as_deref(), Some("src/lib.rs"));
+    assert_eq!(lib.new_path.as_deref(), Some("src/lib.rs"));
+    assert_eq!((lib.additions(), lib.deletions()), (3, 2));
+    assert_eq!(lib.hunks.len(), 2);
+    let hunk = &lib.hunks[0];
+    assert_eq!(
+        (
+            hunk.old_start,
+            hunk.old_lines,
+            hunk.new_start,
+            hunk.new_lines
+        ),
+        (1, 4, 1, 5)
+    );
+    assert_eq!(hunk.section, "mod api;");
+    let lines: Vec<_> = hunk
+        .lines
+        .iter()
+        .map(|l| (l.kind, l.content.as_str(), l.old_line, l.new_line))
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            (LineKind::Context, "mod api;", Some(1), Some(1)),
+            (LineKind::Removed, "mod old;", Some(2), None),
+            (LineKind::Added, "mod new;", None, Some(2)),
+            (LineKind::Added, "mod other;", None, Some(3)),
+            (LineKind::Context, "", Some(3), Some(4)),
+            (LineKind::Context, "pub use api::*;", Some(4), Some(5)),
+        ]
+    );
+    let hunk = &lib.hunks[1];
+    assert_eq!((hunk.old_lines, hunk.new_lines), (1, 1));
+    assert_eq!(hunk.lines.len(), 2);
+    assert_eq!(hunk.lines[1].new_line, Some(21));
+
+    let added = &diff.files[1];
+    assert_eq!(added.old_path, None);
+    assert_eq!(added.path(), "docs/new file.md");
+    assert_eq!(added.hunks[0].lines[0].new_line, Some(1));
+
+    let deleted = &diff.files[2];
+    assert_eq!(deleted.new_path, None);
+    assert_eq!(deleted.path(), "gone.txt");
+    assert_eq!(deleted.deletions(), 1);
+
+    let renamed = &diff.files[3];
+    assert_eq!(renamed.old_path.as_deref(), Some("old.rs"));
+    assert_eq!(renamed.new_path.as_deref(), Some("new.rs"));
+    assert!(renamed.hunks.is_empty());
+
+    let binary = &diff.files[4];
+    assert!(binary.is_binary);
+    assert_eq!(binary.path(), "logo.png");
+
+    assert_eq!(diff.files[5].path(), "café.txt");
+    assert_eq!(diff.files[5].hunks[0].lines[1].content, "coffee");
+}
+
+#[test]
+fn test_parse_patch() {
+    let patch = r#"From 1111111111111111111111111111111111111111 Mon Sep 17 00:00:00 2001
+From: Alice <alice@example.com>
+Date: Mon, 1 Jan 2024 00:00:00 +0000
+Subject: [PATCH 1/2] First
+
+---
+ a.txt | 1 +
+ 1 file changed, 1 insertion(+)
+
+diff --git a/a.txt b/a.txt
+--- a/a.txt
++++ b/a.txt
+@@ -1 +1,2 @@
+ a
++b
+-- 
+2.43.0
+
+From 2222222222222222222222222222222222222222 Mon Sep 17 00:00:00 2001
+From: Alice <alice@example.com>
+Subject: [PATCH 2/2] Second
+
+---
+diff --git a/a.txt b/a.txt
+--- a/a.txt
++++ b/a.txt
+@@ -2 +2 @@
+-b
++c
+-- 
+2.43.0
+"#;
+    let diff = Diff::parse(patch);
+    assert_eq!(diff.files.len(), 2);
+    assert_eq!(diff.files[0].hunks[0].lines.len(), 2);
+    assert_eq!(diff.files[1].hunks[0].lines.len(), 2);
+    assert_eq!(diff.files[1].hunks[0].lines[1].content, "c");
+
+    assert!(Diff::parse("not a diff").files.is_empty());
+}
@@ -0,0 +1,155 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{model::git::GitObjectType, Auth, Client};
+
+#[tokio::test]
+async fn test_trees_and_blobs() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{
+                "sha": "tree1",
+                "truncated": true,
+                "page": 2,
+                "total_count": 3,
+                "tree": [
+                    {"path": "src", "mode": "040000", "type": "tree", "sha": "tree2", "size": 0},
+                    {"path": "src/lib.rs", "mode": "100644", "type": "blob", "sha": "blob1", "size": 12}
+                ]
+            }"#,
+        ),
+        MockResponse::new(
+            200,
+            r#"{"sha": "blob1", "size": 12, "encoding": "base64", "content": "SGVsbG8s\nIHdvcmxk"}"#,
+        ),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let git = client.repos("owner", "repo").git();
+
+    let tree = git
+        .tree("main")
+        .recursive(true)
+        .page(2)
+        .per_page(2)
+        .send(&client)
+        .await
+        .unwrap();
+    assert!(tree.truncated);
+    assert_eq!(tree.tree[0].object_type, GitObjectType::Tree);
+    assert_eq!(tree.tree[1].path, "src/lib.rs");
+    assert_eq!(tree.tree[1].size, 12);
+
+    let blob = git.blob("blob1").send(&client).await.unwrap();
+    assert_eq!(blob.decode().unwrap(), b"Hello, world");
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/api/v1/repos/owner/repo/git/trees/main?recursive=true&page=2&per_page=2"
+    );
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/git/blobs/blob1"
+    );
+}
+
+#[tokio::test]
+async fn test_refs_and_tags() {
+    let reference = r#"{"ref": "refs/tags/v1.0", "url": "", "object": {"type": "tag", "sha": "tag1", "url": ""}}"#;
+    let server = MockServer::start(vec![
+        MockResponse::new(200, format!("[{reference}]")),
+        MockResponse::new(200, format!("[{reference}]")),
+        MockResponse::new(
+            200,
+            r#"{
+                "tag": "v1.0",
+                "sha": "tag1",
+                "message": "First release\n",
+                "tagger": {"name": "Alice", "email": "alice@example.com", "date": "2024-01-01T00:00:00Z"},
+                "object": {"type": "commit", "sha": "commit1", "url": ""}
+            }"#,
+        ),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let git = client.repos("owner", "repo").git();
+
+    let refs = git.refs().send(&client).await.unwrap();
+    assert_eq!(refs[0].r#ref, "refs/tags/v1.0");
+    assert_eq!(refs[0].object.object_type, GitObjectType::Tag);
+    git.get_ref("refs/tags/v1.0").send(&client).await.unwrap();
+
+    let tag = git.tag(&refs[0].object.sha).send(&client).await.unwrap();
+    assert_eq!(tag.tag, "v1.0");
+    assert_eq!(tag.tagger.name, "Alice");
+    assert_eq!(tag.object.object_type, GitObjectType::Commit);
+    assert_eq!(tag.object.sha, "commit1");
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/git/refs");
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/git/refs/tags/v1.0"
+    );
+    assert_eq!(requests[2].target, "/api/v1/repos/owner/repo/git/tags/tag1");
+}
+
+#[tokio::test]
+async fn test_commits_and_notes() {
+    let commit = r#"{"sha": "commit1", "commit": {"message": "Initial commit"}}"#;
+    let server = MockServer::start(vec![
+        MockResponse::new(200, commit),
+        MockResponse::new(
+            200,
+            "diff --git a/a.txt b/a.txt\n--- a/a.txt\n+++ b/a.txt\n@@ -1 +1 @@\n-a\n+b\n",
+        ),
+        MockResponse::new(200, "From commit1 Mon Sep 17 00:00:00 2001\n"),
+        MockResponse::new(
+            200,
+            format!(r#"{{"message": "Reviewed-by: Bob\n", "commit": {commit}}}"#),
+        ),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let git = client.repos("owner", "repo").git();
+
+    let commit = git
+        .commit("commit1")
+        .stat(false)
+        .files(false)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(commit.commit.message, "Initial commit");
+    let diff = git
+        .commit_diff("commit1")
+        .send_parsed(&client)
+        .await
+        .unwrap();
+    assert_eq!(diff.files[0].path(), "a.txt");
+    let patch = git.commit_patch("commit1").send(&client).await.unwrap();
+    assert!(patch.starts_with("From commit1"));
+    let note = git.note("commit1").send(&client).await.unwrap();
+    assert_eq!(note.message, "Reviewed-by: Bob\n");
+    assert_eq!(note.commit.sha, "commit1");
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/api/v1/repos/owner/repo/git/commits/commit1?stat=false&files=false"
+    );
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/git/commits/commit1.diff"
+    );
+    assert_eq!(
+        requests[2].target,
+        "/api/v1/repos/owner/repo/git/commits/commit1.patch"
+    );
+    assert_eq!(
+        requests[3].target,
+        "/api/v1/repos/owner/repo/git/notes/commit1"
+    );
+}
@@ -0,0 +1,168 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{
+    model::hooks::{HookContentType, HookType},
+    Auth, Client,
+};
+
+const HOOK: &str = r#"{
+    "id": 1,
+    "type": "slack",
+    "active": true,
+    "events": ["push", "pull_request"],
+    "config": {"url": "https://hooks.slack.com/services/x", "content_type": "json"}
+}"#;
+
+#[tokio::test]
+async fn test_repo_hooks() {
+    let server = MockServer::start(vec![
+        MockResponse::new(201, HOOK),
+        MockResponse::new(200, format!("[{HOOK}]")),
+        MockResponse::new(200, HOOK),
+        MockResponse::new(200, HOOK),
+        MockResponse::new(204, ""),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let hooks = client.repos("owner", "repo").hooks();
+
+    let hook = hooks
+        .create(HookType::Slack, "https://hooks.slack.com/services/x")
+        .config("channel", "#ci")
+        .events(vec!["push".to_string(), "pull_request".to_string()])
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(hook.r#type, HookType::Slack);
+    assert_eq!(hook.config["url"], "https://hooks.slack.com/services/x");
+    assert_eq!(hook.events, vec!["push", "pull_request"]);
+
+    assert_eq!(hooks.list().send(&client).await.unwrap().len(), 1);
+    hooks.get(1).send(&client).await.unwrap();
+    hooks
+        .edit(1)
+        .url("https://ci.example.com/hook")
+        .content_type(HookContentType::Form)
+        .active(false)
+        .send(&client)
+        .await
+        .unwrap();
+    hooks.delete(1).send(&client).await.unwrap();
+    client
+        .repos("owner", "repo")
+        .test_hook(1)
+        .refs("main")
+        .send(&client)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/hooks");
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "type": "slack",
+            "config": {
+                "url": "https://hooks.slack.com/services/x",
+                "content_type": "json",
+                "channel": "#ci"
+            },
+            "events": ["push", "pull_request"],
+            "active": true
+        })
+    );
+    assert_eq!(requests[1].target, "/api/v1/repos/owner/repo/hooks");
+    assert_eq!(requests[2].target, "/api/v1/repos/owner/repo/hooks/1");
+    assert_eq!(requests[3].method, "PATCH");
+    let body: serde_json::Value = serde_json::from_slice(&requests[3].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "config": {"url": "https://ci.example.com/hook", "content_type": "form"},
+            "active": false
+        })
+    );
+    assert_eq!(requests[4].method, "DELETE");
+    assert_eq!(requests[4].target, "/api/v1/repos/owner/repo/hooks/1");
+    assert_eq!(requests[5].method, "POST");
+    assert_eq!(
+        requests[5].target,
+        "/api/v1/repos/owner/repo/hooks/1/tests?ref=main"
+    );
+}
+
+#[tokio::test]
+async fn test_hook_scopes() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "[]"),
+        MockResponse::new(200, "[]"),
+        MockResponse::new(200, "[]"),
+        MockResponse::new(200, r#"[{"id": 1, "type": "some-new-service"}]"#),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    client
+        .orgs("my-org")
+        .hooks()
+        .list()
+        .send(&client)
+        .await
+        .unwrap();
+    client.user().hooks().list().send(&client).await.unwrap();
+    client.admin().hooks().list().send(&client).await.unwrap();
+    let hooks = client
+        .admin()
+        .hooks()
+        .list()
+        .page(2)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(hooks[0].r#type, HookType::Unknown);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/orgs/my-org/hooks");
+    assert_eq!(requests[1].target, "/api/v1/user/hooks");
+    assert_eq!(requests[2].target, "/api/v1/admin/hooks");
+    assert_eq!(requests[3].target, "/api/v1/admin/hooks?page=2");
+}
+
+#[tokio::test]
+async fn test_git_hooks() {
+    let hook = r##"{"name": "pre-receive", "is_active": true, "content": "#!/bin/sh\nexit 0\n"}"##;
+    let server = MockServer::start(vec![
+        MockResponse::new(200, format!("[{hook}]")),
+        MockResponse::new(200, hook),
+        MockResponse::new(200, hook),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let git_hooks = client.repos("owner", "repo").git_hooks();
+
+    let hooks = git_hooks.list().send(&client).await.unwrap();
+    assert!(hooks[0].is_active);
+    git_hooks.get("pre-receive").send(&client).await.unwrap();
+    let hook = git_hooks
+        .edit("pre-receive", "#!/bin/sh\nexit 0\n")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(hook.content, "#!/bin/sh\nexit 0\n");
+    git_hooks.delete("pre-receive").send(&client).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/hooks/git");
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/hooks/git/pre-receive"
+    );
+    assert_eq!(requests[2].method, "PATCH");
+    assert_eq!(requests[2].body, br##"{"content":"#!/bin/sh\nexit 0\n"}"##);
+    assert_eq!(requests[3].method, "DELETE");
+}
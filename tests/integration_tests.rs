@@ -1,12 +1,14 @@
-use std::{
-    env,
-    io::{self, Write},
-};
+use std::env;
 
-use gitea_sdk::{error::Result, Auth, Client};
+use futures_util::StreamExt;
+use gitea_sdk::{
+    error::{Result, TeatimeErrorKind},
+    pagination::Paginated,
+    Auth, Client,
+};
 use reqwest::Method;
 use testcontainers::{
-    core::{logs::LogFrame, wait::HttpWaitStrategy, IntoContainerPort, WaitFor},
+    core::{wait::HttpWaitStrategy, IntoContainerPort, WaitFor},
     runners::AsyncRunner,
     GenericImage, ImageExt,
 };
@@ -148,6 +150,9 @@ pub async fn test(base_url: &str) -> Result<()> {
     println!("test_create_repo");
     test_create_repo(base_url, &token).await?;
 
+    println!("test_repo_errors");
+    test_repo_errors(base_url, &token).await?;
+
     println!("test_repo_create_branch");
     test_repo_create_branch(base_url, &token).await?;
 
@@ -157,6 +162,12 @@ pub async fn test(base_url: &str) -> Result<()> {
     println!("test_repo_list_branches");
     test_repo_list_branches(base_url, &token).await?;
 
+    println!("test_repo_list_branches_paginated");
+    test_repo_list_branches_paginated(base_url, &token).await?;
+
+    println!("test_repo_list_branches_with_meta");
+    test_repo_list_branches_with_meta(base_url, &token).await?;
+
     println!("test_repo_delete_branch");
     test_repo_delete_branch(base_url, &token).await?;
 
@@ -480,6 +491,28 @@ pub async fn test_create_repo(base_url: &str, token: &str) -> Result<()> {
     Ok(())
 }
 
+pub async fn test_repo_errors(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let err = client
+        .user()
+        .create_repo(GITEA_REPO)
+        .send(&client)
+        .await
+        .expect_err("Repository should already exist");
+    assert_eq!(err.kind, TeatimeErrorKind::Conflict);
+    assert_eq!(err.method, Some(Method::POST));
+    assert_eq!(err.path.as_deref(), Some("/api/v1/user/repos"));
+
+    let err = client
+        .repos(GITEA_USER, "does-not-exist")
+        .get()
+        .send(&client)
+        .await
+        .expect_err("Repository should not exist");
+    assert_eq!(err.kind, TeatimeErrorKind::NotFound);
+    Ok(())
+}
+
 pub async fn test_repo_create_branch(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
     client
@@ -513,6 +546,45 @@ pub async fn test_repo_list_branches(base_url: &str, token: &str) -> Result<()>
     Ok(())
 }
 
+pub async fn test_repo_list_branches_paginated(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let builder = client
+        .repos(GITEA_USER, GITEA_REPO)
+        .list_branches()
+        .limit(1);
+    let branches = builder.send_all(&client).await?;
+    assert_eq!(branches.len(), 2);
+    assert_eq!(branches[0].name, "main");
+
+    let capped = builder.paginate().max_items(1).send_all(&client).await?;
+    assert_eq!(capped.len(), 1);
+
+    let mut stream = builder.stream(&client);
+    let mut count = 0;
+    while let Some(branch) = stream.next().await {
+        branch?;
+        count += 1;
+    }
+    assert_eq!(count, 2);
+    Ok(())
+}
+
+pub async fn test_repo_list_branches_with_meta(base_url: &str, token: &str) -> Result<()> {
+    let client = Client::new(base_url, Auth::Token(token));
+    let res = client
+        .repos(GITEA_USER, GITEA_REPO)
+        .list_branches()
+        .limit(1)
+        .send_with_meta(&client)
+        .await?;
+    assert_eq!(res.body.len(), 1);
+    assert_eq!(res.total_count, Some(2));
+    assert_eq!(res.next_page, Some(2));
+    assert_eq!(res.last_page, Some(2));
+    assert!(res.has_more());
+    Ok(())
+}
+
 pub async fn test_repo_delete_branch(base_url: &str, token: &str) -> Result<()> {
     let client = Client::new(base_url, Auth::Token(token));
     client
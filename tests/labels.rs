@@ -0,0 +1,166 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{Auth, Client};
+
+const LABEL: &str = r##"{"id": 1, "name": "bug", "color": "ee0701", "exclusive": false}"##;
+
+#[tokio::test]
+async fn test_repo_labels() {
+    let server = MockServer::start(vec![
+        MockResponse::new(201, LABEL),
+        MockResponse::new(200, format!("[{LABEL}]")),
+        MockResponse::new(200, LABEL),
+        MockResponse::new(200, LABEL),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let labels = client.repos("owner", "repo").labels();
+
+    let label = labels
+        .create("bug", "#ee0701")
+        .description("Something isn't working")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(label.id, 1);
+    assert_eq!(labels.list().send(&client).await.unwrap().len(), 1);
+    labels.get(1).send(&client).await.unwrap();
+    labels.edit(1).exclusive(true).send(&client).await.unwrap();
+    labels.delete(1).send(&client).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/labels");
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "name": "bug",
+            "color": "#ee0701",
+            "description": "Something isn't working"
+        })
+    );
+    assert_eq!(requests[1].target, "/api/v1/repos/owner/repo/labels");
+    assert_eq!(requests[2].target, "/api/v1/repos/owner/repo/labels/1");
+    assert_eq!(requests[3].method, "PATCH");
+    assert_eq!(requests[3].body, br#"{"exclusive":true}"#);
+    assert_eq!(requests[4].method, "DELETE");
+    assert_eq!(requests[4].target, "/api/v1/repos/owner/repo/labels/1");
+}
+
+#[tokio::test]
+async fn test_org_labels() {
+    let server = MockServer::start(vec![MockResponse::new(200, "[]")]).await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let labels = client
+        .orgs("my org")
+        .labels()
+        .list()
+        .page(2)
+        .send(&client)
+        .await
+        .unwrap();
+    assert!(labels.is_empty());
+    assert_eq!(
+        server.requests()[0].target,
+        "/api/v1/orgs/my%20org/labels?page=2"
+    );
+}
+
+#[tokio::test]
+async fn test_find_label() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, r#"[{"id": 1, "name": "feature"}]"#).header(
+            "link",
+            r#"<https://gitea.example.com/api/v1/repos/owner/repo/labels?page=2>; rel="next""#,
+        ),
+        MockResponse::new(200, format!("[{LABEL}]")),
+        MockResponse::new(200, "[]"),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let labels = client.repos("owner", "repo").labels();
+
+    let label = labels.find("bug").send(&client).await.unwrap();
+    assert_eq!(label.map(|label| label.id), Some(1));
+    let label = labels.find("missing").send(&client).await.unwrap();
+    assert!(label.is_none());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/labels?page=1");
+    assert_eq!(requests[1].target, "/api/v1/repos/owner/repo/labels?page=2");
+}
+
+#[tokio::test]
+async fn test_issue_labels() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, format!("[{LABEL}]")),
+        MockResponse::new(200, format!("[{LABEL}]")),
+        MockResponse::new(200, format!("[{LABEL}]")),
+        MockResponse::new(204, ""),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let issues = client.issues("owner", "repo");
+
+    issues.list_labels(3).send(&client).await.unwrap();
+    let labels = issues
+        .add_labels(3, vec![1, 2])
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(labels[0].name, "bug");
+    issues
+        .replace_labels(3, vec![1])
+        .send(&client)
+        .await
+        .unwrap();
+    issues.remove_label(3, 1).send(&client).await.unwrap();
+    issues.clear_labels(3).send(&client).await.unwrap();
+
+    let requests = server.requests();
+    let path = "/api/v1/repos/owner/repo/issues/3/labels";
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].target, path);
+    assert_eq!(requests[1].method, "POST");
+    assert_eq!(requests[1].target, path);
+    assert_eq!(requests[1].body, br#"{"labels":[1,2]}"#);
+    assert_eq!(requests[2].method, "PUT");
+    assert_eq!(requests[2].body, br#"{"labels":[1]}"#);
+    assert_eq!(requests[3].method, "DELETE");
+    assert_eq!(requests[3].target, format!("{path}/1"));
+    assert_eq!(requests[4].method, "DELETE");
+    assert_eq!(requests[4].target, path);
+}
+
+#[tokio::test]
+async fn test_label_templates() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, r#"["Default", "Advanced"]"#),
+        MockResponse::new(
+            200,
+            r#"[{"name": "Kind/Bug", "color": "ee0701", "exclusive": true}]"#,
+        ),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let names = client.label_templates().list().send(&client).await.unwrap();
+    assert_eq!(names, vec!["Default", "Advanced"]);
+    let labels = client
+        .label_templates()
+        .get("Advanced")
+        .send(&client)
+        .await
+        .unwrap();
+    assert!(labels[0].exclusive);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/label/templates");
+    assert_eq!(requests[1].target, "/api/v1/label/templates/Advanced");
+}
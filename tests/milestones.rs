@@ -0,0 +1,113 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{
+    model::issues::{State, StateType},
+    Auth, Client,
+};
+
+const MILESTONE: &str = r#"{
+    "id": 1,
+    "title": "Sprint 12",
+    "state": "open",
+    "open_issues": 3,
+    "closed_issues": 2,
+    "due_on": "2024-03-31T00:00:00Z",
+    "closed_at": null
+}"#;
+
+#[tokio::test]
+async fn test_milestones() {
+    let server = MockServer::start(vec![
+        MockResponse::new(201, MILESTONE),
+        MockResponse::new(200, format!("[{MILESTONE}]")),
+        MockResponse::new(200, MILESTONE),
+        MockResponse::new(200, MILESTONE),
+        MockResponse::new(200, MILESTONE),
+        MockResponse::new(200, MILESTONE),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let milestones = client.repos("owner", "repo").milestones();
+
+    let milestone = milestones
+        .create("Sprint 12")
+        .description("Second half of March")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(milestone.title, "Sprint 12");
+    assert!(matches!(milestone.state, StateType::Open));
+    assert_eq!(milestone.open_issues, 3);
+    assert!(milestone.closed_at.is_none());
+
+    let list = milestones
+        .list()
+        .state(State::All)
+        .name("Sprint 12")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(list.len(), 1);
+    milestones.get(1).send(&client).await.unwrap();
+    milestones
+        .edit(1)
+        .title("Sprint 13")
+        .send(&client)
+        .await
+        .unwrap();
+    milestones.close(1).send(&client).await.unwrap();
+    milestones.reopen(1).send(&client).await.unwrap();
+    milestones.delete(1).send(&client).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/milestones");
+    assert_eq!(
+        requests[0].body,
+        br#"{"title":"Sprint 12","description":"Second half of March"}"#
+    );
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/milestones?state=all&name=Sprint+12"
+    );
+    assert_eq!(requests[2].target, "/api/v1/repos/owner/repo/milestones/1");
+    assert_eq!(requests[3].method, "PATCH");
+    assert_eq!(requests[3].body, br#"{"title":"Sprint 13"}"#);
+    assert_eq!(requests[4].body, br#"{"state":"closed"}"#);
+    assert_eq!(requests[5].body, br#"{"state":"open"}"#);
+    assert_eq!(requests[6].method, "DELETE");
+    assert_eq!(requests[6].target, "/api/v1/repos/owner/repo/milestones/1");
+}
+
+#[tokio::test]
+async fn test_issue_milestone() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            format!(r#"{{"number": 1, "title": "Bug", "milestone": {MILESTONE}}}"#),
+        ),
+        MockResponse::new(
+            200,
+            r#"{"number": 2, "title": "Feature", "milestone": null}"#,
+        ),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let issue = client
+        .issues("owner", "repo")
+        .get(1)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(issue.milestone.unwrap().id, 1);
+    let issue = client
+        .issues("owner", "repo")
+        .get(2)
+        .send(&client)
+        .await
+        .unwrap();
+    assert!(issue.milestone.is_none());
+}
@@ -0,0 +1,226 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{error::TeatimeErrorKind, pagination::Paginated, Auth, Client};
+
+const TWO_USERS: &str = r#"[{"id": 1, "login": "a"}, {"id": 2, "login": "b"}]"#;
+const ONE_USER: &str = r#"[{"id": 3, "login": "c"}]"#;
+
+fn pages(server: &MockServer) -> Vec<String> {
+    server
+        .requests()
+        .into_iter()
+        .map(|request| request.target)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_stops_without_next_link() {
+    let link = |rels: &[(&str, i64)]| {
+        rels.iter()
+            .map(|(rel, page)| {
+                format!(
+                    r#"<https://gitea.example.com/api/v1/orgs/o/members?limit=2&page={page}>; rel="{rel}""#
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    // Both pages are full and there is no total count, so only the Link header can tell that
+    // the second page is the last one.
+    let server = MockServer::start(vec![
+        MockResponse::new(200, TWO_USERS).header("link", link(&[("next", 2), ("last", 2)])),
+        MockResponse::new(200, TWO_USERS).header("link", link(&[("first", 1), ("prev", 1)])),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let users = client
+        .orgs("o")
+        .list_members()
+        .limit(2)
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert_eq!(users.len(), 4);
+    assert_eq!(
+        pages(&server),
+        [
+            "/api/v1/orgs/o/members?page=1&limit=2",
+            "/api/v1/orgs/o/members?page=2&limit=2",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_stops_at_total_count() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, TWO_USERS).header("x-total-count", "4"),
+        MockResponse::new(200, TWO_USERS).header("x-total-count", "4"),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let users = client
+        .orgs("o")
+        .list_members()
+        .limit(2)
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert_eq!(users.len(), 4);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_stops_at_short_page() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, TWO_USERS),
+        MockResponse::new(200, ONE_USER),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let users = client
+        .orgs("o")
+        .list_members()
+        .limit(2)
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert_eq!(users.len(), 3);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_full_last_page_stops_at_empty_page() {
+    // Without a Link header or a total count, a full page could be followed by more items, so
+    // the next page is fetched and the empty result ends the loop.
+    let server = MockServer::start(vec![
+        MockResponse::new(200, TWO_USERS),
+        MockResponse::new(200, TWO_USERS),
+        MockResponse::new(200, "[]"),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let users = client
+        .orgs("o")
+        .list_members()
+        .limit(2)
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert_eq!(users.len(), 4);
+    assert_eq!(
+        pages(&server),
+        [
+            "/api/v1/orgs/o/members?page=1&limit=2",
+            "/api/v1/orgs/o/members?page=2&limit=2",
+            "/api/v1/orgs/o/members?page=3&limit=2",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_empty_first_page() {
+    let server = MockServer::start(vec![MockResponse::new(200, "[]")]).await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let users = client
+        .orgs("o")
+        .list_members()
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert!(users.is_empty());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_max_items_cuts_page_short() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, TWO_USERS),
+        MockResponse::new(200, TWO_USERS),
+        MockResponse::new(200, TWO_USERS),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let users = client
+        .orgs("o")
+        .list_members()
+        .limit(2)
+        .paginate()
+        .max_items(3)
+        .send_all(&client)
+        .await
+        .unwrap();
+    let logins: Vec<_> = users.iter().map(|user| user.login.as_str()).collect();
+    assert_eq!(logins, ["a", "b", "a"]);
+    // The third item comes from the second page, and no page after it is fetched.
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_starts_at_given_page() {
+    let server = MockServer::start(vec![MockResponse::new(200, ONE_USER)]).await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let users = client
+        .orgs("o")
+        .list_members()
+        .page(3)
+        .limit(2)
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert_eq!(users.len(), 1);
+    assert_eq!(pages(&server), ["/api/v1/orgs/o/members?page=3&limit=2"]);
+}
+
+#[tokio::test]
+async fn test_total_count_from_given_page() {
+    // The total counts the items of the first page too, so the third page is the last one.
+    let server = MockServer::start(vec![
+        MockResponse::new(200, TWO_USERS).header("x-total-count", "6"),
+        MockResponse::new(200, TWO_USERS).header("x-total-count", "6"),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let users = client
+        .orgs("o")
+        .list_members()
+        .page(2)
+        .limit(2)
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert_eq!(users.len(), 4);
+    assert_eq!(
+        pages(&server),
+        [
+            "/api/v1/orgs/o/members?page=2&limit=2",
+            "/api/v1/orgs/o/members?page=3&limit=2",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_items_are_decode_errors() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, r#"[{"id": "one"}]"#),
+        MockResponse::new(200, r#"[{"id": "one"}]"#),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let list = client.orgs("o").list_members();
+
+    let err = list.send(&client).await.unwrap_err();
+    assert_eq!(err.kind, TeatimeErrorKind::Decode);
+    let err = list.send_all(&client).await.unwrap_err();
+    assert_eq!(err.kind, TeatimeErrorKind::Decode);
+    assert_eq!(err.path.as_deref(), Some("/api/v1/orgs/o/members"));
+    assert_eq!(err.status_code, Some(reqwest::StatusCode::OK));
+}
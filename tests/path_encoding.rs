@@ -0,0 +1,94 @@
+mod common;
+
+use common::MockServer;
+use gitea_sdk::{Auth, Client};
+
+/// Starts a mock server and returns a client for it. The mock server's empty responses don't
+/// decode into most models, so the tests below ignore the results and only check the URLs.
+async fn setup() -> (MockServer, Client) {
+    let server = MockServer::start(vec![]).await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_branch_names() {
+    let (server, client) = setup().await;
+    let repo = client.repos("owner", "repo");
+    let _ = repo.get_branch("feature/x#1").send(&client).await;
+    let _ = repo.delete_branch("fix/50% off?").send(&client).await;
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/api/v1/repos/owner/repo/branches/feature/x%231"
+    );
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/branches/fix/50%25%20off%3F"
+    );
+}
+
+#[tokio::test]
+async fn test_file_paths() {
+    let (server, client) = setup().await;
+    let contents = client.repos("owner", "repo").contents();
+    let _ = contents
+        .get("docs/über uns/[draft] #1?.md")
+        .refs("main")
+        .send(&client)
+        .await;
+    let _ = contents
+        .delete_file("dir/a&b=c.txt", "sha")
+        .send(&client)
+        .await;
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/api/v1/repos/owner/repo/contents/docs/%C3%BCber%20uns/%5Bdraft%5D%20%231%3F.md?ref=main"
+    );
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/contents/dir/a&b=c.txt"
+    );
+}
+
+#[tokio::test]
+async fn test_single_segments() {
+    let (server, client) = setup().await;
+    let _ = client.repos("own er", "re/po").get().send(&client).await;
+    let _ = client.orgs("org#1").get().send(&client).await;
+    let _ = client.users("日本").get().send(&client).await;
+    let _ = client
+        .user()
+        .delete_access_token("user", "my token/1")
+        .send(&client)
+        .await;
+
+    let targets: Vec<_> = server.requests().into_iter().map(|r| r.target).collect();
+    assert_eq!(
+        targets,
+        [
+            "/api/v1/repos/own%20er/re%2Fpo",
+            "/api/v1/orgs/org%231",
+            "/api/v1/users/%E6%97%A5%E6%9C%AC",
+            "/api/v1/users/user/tokens/my%20token%2F1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_pull_by_branches() {
+    let (server, client) = setup().await;
+    let _ = client
+        .pulls("owner", "repo")
+        .get_by_branches("feature/#2", "main")
+        .send(&client)
+        .await;
+
+    assert_eq!(
+        server.requests()[0].target,
+        "/api/v1/repos/owner/repo/pulls/main/feature/%232"
+    );
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{api::pulls::files::WhitespaceBehavior, model::pulls::FileStatus, Auth, Client};
+
+const DIFF: &str = "diff --git a/a.txt b/a.txt\n--- a/a.txt\n+++ b/a.txt\n@@ -1 +1 @@\n-a\n+b\n";
+
+#[tokio::test]
+async fn test_diff_and_patch() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, DIFF),
+        MockResponse::new(200, DIFF),
+        MockResponse::new(200, "From 123 Mon Sep 17 00:00:00 2001\n"),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let pulls = client.pulls("owner", "repo");
+
+    let raw = pulls.diff(3).send(&client).await.unwrap();
+    assert_eq!(raw, DIFF);
+    let diff = pulls
+        .diff(3)
+        .binary(true)
+        .send_parsed(&client)
+        .await
+        .unwrap();
+    assert_eq!(diff.files[0].path(), "a.txt");
+    assert_eq!(diff.files[0].additions(), 1);
+    let patch = pulls.patch(3).send(&client).await.unwrap();
+    assert!(patch.starts_with("From 123"));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/pulls/3.diff");
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/pulls/3.diff?binary=true"
+    );
+    assert_eq!(requests[2].target, "/api/v1/repos/owner/repo/pulls/3.patch");
+}
+
+#[tokio::test]
+async fn test_commits_and_files() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"[{"sha": "abc", "commit": {"message": "Fix it"}, "author": null}]"#,
+        ),
+        MockResponse::new(
+            200,
+            r#"[
+                {"filename": "src/new.rs", "previous_filename": "src/old.rs", "status": "renamed", "additions": 2, "deletions": 1, "changes": 3},
+                {"filename": "README.md", "status": "added", "additions": 10, "deletions": 0, "changes": 10},
+                {"filename": "x", "status": "something-new"}
+            ]"#,
+        ),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let pulls = client.pulls("owner", "repo");
+
+    let commits = pulls
+        .commits(3)
+        .files(false)
+        .verification(false)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(commits[0].sha, "abc");
+    assert_eq!(commits[0].commit.message, "Fix it");
+
+    let files = pulls
+        .files(3)
+        .whitespace(WhitespaceBehavior::IgnoreEol)
+        .limit(50)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(files[0].status, FileStatus::Renamed);
+    assert_eq!(files[0].previous_filename.as_deref(), Some("src/old.rs"));
+    assert_eq!((files[0].additions, files[0].deletions), (2, 1));
+    assert_eq!(files[1].status, FileStatus::Added);
+    assert_eq!(files[1].previous_filename, None);
+    assert_eq!(files[2].status, FileStatus::Unknown);
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/api/v1/repos/owner/repo/pulls/3/commits?verification=false&files=false"
+    );
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/pulls/3/files?whitespace=ignore-eol&limit=50"
+    );
+}
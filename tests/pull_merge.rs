@@ -0,0 +1,137 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{
+    model::pulls::{MergeResult, MergeStyle, UpdateStyle},
+    Auth, Client,
+};
+
+#[tokio::test]
+async fn test_merge_styles() {
+    let styles = [
+        (MergeStyle::Merge, "merge"),
+        (MergeStyle::Rebase, "rebase"),
+        (MergeStyle::RebaseMerge, "rebase-merge"),
+        (MergeStyle::Squash, "squash"),
+        (MergeStyle::FastForwardOnly, "fast-forward-only"),
+        (MergeStyle::ManuallyMerged, "manually-merged"),
+    ];
+    let server =
+        MockServer::start(styles.iter().map(|_| MockResponse::new(200, "")).collect()).await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    for (style, _) in styles {
+        let result = client
+            .pulls("owner", "repo")
+            .merge(7, style)
+            .send(&client)
+            .await
+            .unwrap();
+        assert_eq!(result, MergeResult::Merged);
+    }
+
+    let requests = server.requests();
+    for (request, (_, name)) in requests.iter().zip(styles) {
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.target, "/api/v1/repos/owner/repo/pulls/7/merge");
+        assert_eq!(request.body, format!(r#"{{"Do":"{name}"}}"#).as_bytes());
+    }
+}
+
+#[tokio::test]
+async fn test_merge_options() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, ""),
+        MockResponse::new(200, ""),
+        MockResponse::new(201, ""),
+        MockResponse::new(204, ""),
+        MockResponse::new(405, r#"{"message": "Please try again later"}"#),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let pulls = client.pulls("owner", "repo");
+
+    pulls
+        .merge(1, MergeStyle::Squash)
+        .title("Add feature (#1)")
+        .message("Squashed commits")
+        .head_commit_id("abc123")
+        .delete_branch_after_merge(true)
+        .force_merge(false)
+        .send(&client)
+        .await
+        .unwrap();
+    pulls
+        .merge(2, MergeStyle::ManuallyMerged)
+        .merge_commit_id("def456")
+        .send(&client)
+        .await
+        .unwrap();
+    let result = pulls
+        .merge(3, MergeStyle::Merge)
+        .merge_when_checks_succeed(true)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(result, MergeResult::Scheduled);
+    pulls.cancel_scheduled_merge(3).send(&client).await.unwrap();
+    let err = pulls
+        .merge(4, MergeStyle::Rebase)
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status_code.map(|s| s.as_u16()), Some(405));
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].body,
+        br#"{"Do":"squash","MergeTitleField":"Add feature (#1)","MergeMessageField":"Squashed commits","head_commit_id":"abc123","delete_branch_after_merge":true,"force_merge":false}"#
+    );
+    assert_eq!(
+        requests[1].body,
+        br#"{"Do":"manually-merged","MergeCommitID":"def456"}"#
+    );
+    assert_eq!(
+        requests[2].body,
+        br#"{"Do":"merge","merge_when_checks_succeed":true}"#
+    );
+    assert_eq!(requests[3].method, "DELETE");
+    assert_eq!(requests[3].target, "/api/v1/repos/owner/repo/pulls/3/merge");
+}
+
+#[tokio::test]
+async fn test_is_merged_and_update() {
+    let server = MockServer::start(vec![
+        MockResponse::new(204, ""),
+        MockResponse::new(404, ""),
+        MockResponse::new(200, ""),
+        MockResponse::new(200, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let pulls = client.pulls("owner", "repo");
+
+    assert!(pulls.is_merged(1).send(&client).await.unwrap());
+    assert!(!pulls.is_merged(2).send(&client).await.unwrap());
+    pulls.update(1).send(&client).await.unwrap();
+    pulls
+        .update(1)
+        .style(UpdateStyle::Rebase)
+        .send(&client)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/pulls/1/merge");
+    assert_eq!(requests[1].target, "/api/v1/repos/owner/repo/pulls/2/merge");
+    assert_eq!(requests[2].method, "POST");
+    assert_eq!(
+        requests[2].target,
+        "/api/v1/repos/owner/repo/pulls/1/update"
+    );
+    assert_eq!(
+        requests[3].target,
+        "/api/v1/repos/owner/repo/pulls/1/update?style=rebase"
+    );
+}
@@ -0,0 +1,215 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{pagination::Paginated, Auth, Client};
+
+const RELEASE: &str = r#"{
+    "id": 1,
+    "tag_name": "v1.0.0",
+    "name": "Version 1.0.0",
+    "draft": false,
+    "prerelease": true,
+    "assets": [{"id": 2, "name": "app.tar.gz", "size": 5}]
+}"#;
+
+#[tokio::test]
+async fn test_releases() {
+    let server = MockServer::start(vec![
+        MockResponse::new(201, RELEASE),
+        MockResponse::new(200, RELEASE),
+        MockResponse::new(200, RELEASE),
+        MockResponse::new(200, format!("[{RELEASE}]")),
+        MockResponse::new(200, RELEASE),
+        MockResponse::new(204, ""),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let releases = client.repos("owner", "repo").releases();
+
+    let release = releases
+        .create("v1.0.0")
+        .name("Version 1.0.0")
+        .prerelease(true)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(release.tag_name, "v1.0.0");
+    assert!(release.prerelease);
+    assert_eq!(release.assets[0].name, "app.tar.gz");
+
+    releases.latest().send(&client).await.unwrap();
+    releases
+        .get_by_tag("release/1.0")
+        .send(&client)
+        .await
+        .unwrap();
+    let list = releases
+        .list()
+        .pre_release(false)
+        .limit(10)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(list.len(), 1);
+    releases.edit(1).draft(true).send(&client).await.unwrap();
+    releases.delete(1).send(&client).await.unwrap();
+    releases
+        .delete_by_tag("v1.0.0")
+        .send(&client)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/releases");
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({"tag_name": "v1.0.0", "name": "Version 1.0.0", "prerelease": true})
+    );
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/releases/latest"
+    );
+    assert_eq!(
+        requests[2].target,
+        "/api/v1/repos/owner/repo/releases/tags/release%2F1.0"
+    );
+    assert_eq!(
+        requests[3].target,
+        "/api/v1/repos/owner/repo/releases?pre-release=false&limit=10"
+    );
+    assert_eq!(requests[4].method, "PATCH");
+    assert_eq!(requests[4].target, "/api/v1/repos/owner/repo/releases/1");
+    assert_eq!(requests[4].body, br#"{"draft":true}"#);
+    assert_eq!(requests[5].method, "DELETE");
+    assert_eq!(requests[5].target, "/api/v1/repos/owner/repo/releases/1");
+    assert_eq!(
+        requests[6].target,
+        "/api/v1/repos/owner/repo/releases/tags/v1.0.0"
+    );
+}
+
+#[tokio::test]
+async fn test_upload_asset() {
+    let server = MockServer::start(vec![MockResponse::new(
+        201,
+        r#"{"id": 2, "name": "app.tar.gz", "size": 5}"#,
+    )])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let asset = client
+        .repos("owner", "repo")
+        .releases()
+        .upload_asset(1, "app.tar.gz", b"hello".to_vec())
+        .content_type("application/gzip")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(asset.id, 2);
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(
+        request.target,
+        "/api/v1/repos/owner/repo/releases/1/assets?name=app.tar.gz"
+    );
+    assert!(request
+        .header("content-type")
+        .unwrap()
+        .starts_with("multipart/form-data; boundary="));
+    let body = String::from_utf8_lossy(&request.body);
+    assert!(body.contains(r#"name="attachment"; filename="app.tar.gz""#));
+    assert!(body
+        .to_lowercase()
+        .contains("content-type: application/gzip"));
+    assert!(body.contains("\r\n\r\nhello\r\n"));
+}
+
+#[tokio::test]
+async fn test_download_asset() {
+    // Attachments are downloaded from their browser_download_url, served here by a second server
+    // on another port, which must not get the client's credentials.
+    let files = MockServer::start(vec![MockResponse::new(200, "hello")]).await;
+    let api = MockServer::start(vec![MockResponse::new(
+        200,
+        format!(
+            r#"{{"id": 2, "name": "app.tar.gz", "browser_download_url": "{}/owner/repo/releases/download/v1.0.0/app.tar.gz"}}"#,
+            files.url
+        ),
+    )])
+    .await;
+    let client = Client::new(&api.url, Auth::Token("token"));
+
+    let download = client
+        .repos("owner", "repo")
+        .releases()
+        .download_asset(1, 2)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(download.asset.name, "app.tar.gz");
+    assert_eq!(download.content_length(), Some(5));
+    assert_eq!(download.bytes().await.unwrap().as_ref(), b"hello");
+
+    assert_eq!(
+        api.requests()[0].target,
+        "/api/v1/repos/owner/repo/releases/1/assets/2"
+    );
+    let request = &files.requests()[0];
+    assert_eq!(
+        request.target,
+        "/owner/repo/releases/download/v1.0.0/app.tar.gz"
+    );
+    assert_eq!(request.header("authorization"), None);
+    assert_eq!(request.header("user-agent"), None);
+}
+
+#[tokio::test]
+async fn test_download_asset_from_gitea() {
+    let server = MockServer::start(vec![]).await;
+    let asset = format!(
+        r#"{{"id": 2, "name": "app.tar.gz", "browser_download_url": "{}/owner/repo/releases/download/v1.0.0/app.tar.gz"}}"#,
+        server.url
+    );
+    server.push(MockResponse::new(200, asset));
+    server.push(MockResponse::new(200, "hello"));
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let download = client
+        .repos("owner", "repo")
+        .releases()
+        .download_asset(1, 2)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(download.bytes().await.unwrap().as_ref(), b"hello");
+
+    let request = &server.requests()[1];
+    assert_eq!(
+        request.target,
+        "/owner/repo/releases/download/v1.0.0/app.tar.gz"
+    );
+    assert_eq!(request.header("authorization"), Some("token token"));
+}
+
+#[tokio::test]
+async fn test_list_all_releases() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, format!("[{RELEASE}, {RELEASE}]")),
+        MockResponse::new(200, "[]"),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let releases = client
+        .repos("owner", "repo")
+        .releases()
+        .list()
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert_eq!(releases.len(), 2);
+}
@@ -0,0 +1,103 @@
+use gitea_sdk::response::ApiResponse;
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::StatusCode;
+
+fn response(headers: &[(&'static str, &str)]) -> ApiResponse<()> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        map.append(*name, HeaderValue::from_str(value).unwrap());
+    }
+    ApiResponse::new((), StatusCode::OK, map)
+}
+
+#[test]
+fn test_link_header_with_several_relations() {
+    // The format Gitea sends, with the relations separated by commas and no spaces.
+    let res = response(&[
+        (
+            "link",
+            concat!(
+                r#"<https://gitea.example.com/api/v1/repos/owner/repo/issues?limit=2&page=3&state=open>; rel="next","#,
+                r#"<https://gitea.example.com/api/v1/repos/owner/repo/issues?limit=2&page=5&state=open>; rel="last","#,
+                r#"<https://gitea.example.com/api/v1/repos/owner/repo/issues?limit=2&page=1&state=open>; rel="first","#,
+                r#"<https://gitea.example.com/api/v1/repos/owner/repo/issues?limit=2&page=1&state=open>; rel="prev""#,
+            ),
+        ),
+        ("x-total-count", "9"),
+    ]);
+    assert_eq!(res.next_page, Some(3));
+    assert_eq!(res.last_page, Some(5));
+    assert_eq!(res.first_page, Some(1));
+    assert_eq!(res.prev_page, Some(1));
+    assert_eq!(res.total_count, Some(9));
+    assert!(res.has_more());
+}
+
+#[test]
+fn test_link_header_on_last_page() {
+    let res = response(&[(
+        "link",
+        concat!(
+            r#"<https://gitea.example.com/api/v1/user/repos?page=1&limit=2>; rel="first", "#,
+            r#"<https://gitea.example.com/api/v1/user/repos?page=4&limit=2>; rel="prev""#,
+        ),
+    )]);
+    assert_eq!(res.next_page, None);
+    assert_eq!(res.last_page, None);
+    assert_eq!(res.prev_page, Some(4));
+    assert!(!res.has_more());
+}
+
+#[test]
+fn test_link_header_split_across_values() {
+    let res = response(&[
+        (
+            "link",
+            r#"<https://gitea.example.com/api/v1/orgs?page=2&limit=2>; rel="next""#,
+        ),
+        (
+            "link",
+            r#"<https://gitea.example.com/api/v1/orgs?page=7&limit=2>; rel="last""#,
+        ),
+    ]);
+    assert_eq!(res.next_page, Some(2));
+    assert_eq!(res.last_page, Some(7));
+}
+
+#[test]
+fn test_missing_and_invalid_headers() {
+    let res = response(&[]);
+    assert_eq!(res.next_page, None);
+    assert_eq!(res.total_count, None);
+    assert!(!res.has_more());
+
+    let res = response(&[("link", "not a link"), ("x-total-count", "many")]);
+    assert_eq!(res.next_page, None);
+    assert_eq!(res.total_count, None);
+}
+
+#[test]
+fn test_has_more_header() {
+    // Endpoints that can't count their items send X-HasMore instead of a total count, which
+    // takes precedence over the Link header.
+    let res = response(&[
+        (
+            "link",
+            r#"<https://gitea.example.com/api/v1/repos/owner/repo/commits?page=2>; rel="next""#,
+        ),
+        ("x-hasmore", "false"),
+    ]);
+    assert_eq!(res.next_page, Some(2));
+    assert!(!res.has_more());
+
+    assert!(response(&[("x-hasmore", "true")]).has_more());
+    assert!(response(&[("X-HasMore", "True")]).has_more());
+}
+
+#[test]
+fn test_etag_and_map() {
+    let res = response(&[("etag", r#""abc123""#)]).map(|()| 42);
+    assert_eq!(res.etag(), Some(r#""abc123""#));
+    assert_eq!(res.status, StatusCode::OK);
+    assert_eq!(res.into_body(), 42);
+}
@@ -0,0 +1,259 @@
+mod common;
+
+use std::time::{Duration, Instant, SystemTime};
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{error::TeatimeErrorKind, retry::RetryPolicy, Auth, Client};
+use reqwest::{Method, StatusCode};
+
+fn client(server: &MockServer, policy: RetryPolicy) -> Client {
+    Client::new(&server.url, Auth::Token("token")).with_retry_policy(policy)
+}
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::new()
+        .initial_backoff(Duration::from_millis(1))
+        .jitter(false)
+}
+
+#[tokio::test]
+async fn test_no_retry_by_default() {
+    let server = MockServer::start(vec![MockResponse::new(502, "bad gateway")]).await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let err = client.repos("owner", "repo").get().send(&client).await;
+    assert_eq!(err.unwrap_err().status_code, Some(StatusCode::BAD_GATEWAY));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_retries_idempotent_request() {
+    let server = MockServer::start(vec![
+        MockResponse::new(502, "bad gateway"),
+        MockResponse::new(503, "unavailable"),
+        MockResponse::new(200, r#"{"name": "repo"}"#),
+    ])
+    .await;
+    let client = client(&server, fast_policy());
+    let repo = client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(repo.name, "repo");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests.iter().all(|r| r.method == "GET"));
+    assert!(requests
+        .iter()
+        .all(|r| r.target == "/api/v1/repos/owner/repo"));
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts() {
+    let server = MockServer::start(vec![
+        MockResponse::new(502, "bad gateway"),
+        MockResponse::new(502, "bad gateway"),
+        MockResponse::new(502, "bad gateway"),
+        MockResponse::new(200, "{}"),
+    ])
+    .await;
+    let client = client(&server, fast_policy().max_attempts(3));
+    let err = client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status_code, Some(StatusCode::BAD_GATEWAY));
+    assert_eq!(err.method, Some(Method::GET));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_does_not_retry_post_by_default() {
+    let server = MockServer::start(vec![
+        MockResponse::new(502, "bad gateway"),
+        MockResponse::new(200, "{}"),
+    ])
+    .await;
+    let client = client(&server, fast_policy());
+    let err = client
+        .user()
+        .create_repo("repo")
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status_code, Some(StatusCode::BAD_GATEWAY));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_retries_post_if_configured() {
+    let server = MockServer::start(vec![
+        MockResponse::new(502, "bad gateway"),
+        MockResponse::new(201, r#"{"name": "repo"}"#),
+    ])
+    .await;
+    let client = client(&server, fast_policy().retry_methods([Method::POST]));
+    let repo = client
+        .user()
+        .create_repo("repo")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(repo.name, "repo");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    // The body has to be sent again on the retry.
+    assert_eq!(requests[0].body, requests[1].body);
+    assert!(!requests[1].body.is_empty());
+}
+
+#[tokio::test]
+async fn test_does_not_retry_other_statuses() {
+    let server = MockServer::start(vec![
+        MockResponse::new(404, r#"{"message": "not found", "url": "", "errors": []}"#),
+        MockResponse::new(200, "{}"),
+    ])
+    .await;
+    let client = client(&server, fast_policy());
+    let err = client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind, TeatimeErrorKind::NotFound);
+    assert_eq!(err.message, "not found");
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_honors_retry_after() {
+    let server = MockServer::start(vec![
+        MockResponse::new(429, "slow down").header("Retry-After", "1"),
+        MockResponse::new(200, "{}"),
+    ])
+    .await;
+    let client = client(&server, fast_policy());
+    let start = Instant::now();
+    client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_retry_after_is_capped_by_max_backoff() {
+    let server = MockServer::start(vec![
+        MockResponse::new(503, "unavailable").header("Retry-After", "3600"),
+        MockResponse::new(200, "{}"),
+    ])
+    .await;
+    let client = client(
+        &server,
+        fast_policy().max_backoff(Duration::from_millis(10)),
+    );
+    let start = Instant::now();
+    client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_honors_retry_after_date() {
+    let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(3));
+    let server = MockServer::start(vec![
+        MockResponse::new(503, "unavailable").header("Retry-After", date),
+        MockResponse::new(200, "{}"),
+    ])
+    .await;
+    let client = client(&server, fast_policy());
+    let start = Instant::now();
+    client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    // The date has a resolution of one second, so the delay can be up to a second shorter.
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_retry_after_date_in_the_past() {
+    let date = httpdate::fmt_http_date(SystemTime::now() - Duration::from_secs(60));
+    let server = MockServer::start(vec![
+        MockResponse::new(429, "slow down").header("Retry-After", date),
+        MockResponse::new(200, "{}"),
+    ])
+    .await;
+    // A date that already passed replaces the long backoff with no delay at all.
+    let policy = fast_policy()
+        .initial_backoff(Duration::from_secs(30))
+        .max_backoff(Duration::from_secs(30));
+    let client = client(&server, policy);
+    let start = Instant::now();
+    client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_retry_after_date_is_capped_by_max_backoff() {
+    let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(3600));
+    let server = MockServer::start(vec![
+        MockResponse::new(503, "unavailable").header("Retry-After", date),
+        MockResponse::new(200, "{}"),
+    ])
+    .await;
+    let client = client(
+        &server,
+        fast_policy().max_backoff(Duration::from_millis(10)),
+    );
+    let start = Instant::now();
+    client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_connection_errors_after_retries() {
+    // Bind and immediately drop a listener to get a local port nothing listens on.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let client = Client::new(&url, Auth::Token("token")).with_retry_policy(fast_policy());
+    let err = client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind, TeatimeErrorKind::Connect);
+    assert_eq!(err.status_code, None);
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{
+    model::reviews::{CreateReviewComment, ReviewStateType},
+    Auth, Client,
+};
+
+fn review(id: i64, state: &str) -> String {
+    format!(
+        r#"{{
+            "id": {id},
+            "body": "",
+            "comments_count": 1,
+            "commit_id": "abc",
+            "dismissed": false,
+            "html_url": "",
+            "official": true,
+            "pull_request_url": "",
+            "stale": false,
+            "state": "{state}",
+            "submitted_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z",
+            "team": null,
+            "user": null
+        }}"#
+    )
+}
+
+const COMMENT: &str = r#"{
+    "id": 7,
+    "body": "Typo here",
+    "path": "src/lib.rs",
+    "position": 12,
+    "original_position": 0,
+    "pull_request_review_id": 42,
+    "diff_hunk": "@@ -10,3 +10,3 @@",
+    "created_at": "2024-01-01T00:00:00Z",
+    "updated_at": "2024-01-01T00:00:00Z",
+    "resolver": null
+}"#;
+
+#[tokio::test]
+async fn test_review_lifecycle() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, review(42, "PENDING")),
+        MockResponse::new(200, review(42, "APPROVED")),
+        MockResponse::new(200, review(42, "APPROVED")),
+        MockResponse::new(200, format!("[{COMMENT}]")),
+        MockResponse::new(200, COMMENT),
+        MockResponse::new(200, review(42, "APPROVED")),
+        MockResponse::new(200, review(42, "APPROVED")),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let reviews = client.pulls("owner", "repo").reviews();
+
+    let review = reviews
+        .create(1)
+        .body("Nits")
+        .comment(CreateReviewComment::new_line("src/lib.rs", 12, "Typo here"))
+        .comment(CreateReviewComment::old_line(
+            "src/old.rs",
+            3,
+            "Why remove this?",
+        ))
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(review.state, ReviewStateType::Pending);
+    let review = reviews
+        .submit(1, 42, ReviewStateType::Approved)
+        .body("LGTM")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(review.state, ReviewStateType::Approved);
+    reviews.get_by_id(1, 42).send(&client).await.unwrap();
+
+    let comments = reviews.comments(1, 42).send(&client).await.unwrap();
+    assert_eq!(comments[0].path, "src/lib.rs");
+    assert_eq!(comments[0].position, 12);
+    assert_eq!(comments[0].pull_request_review_id, 42);
+    let comment = reviews.get_comment(1, 42, 7).send(&client).await.unwrap();
+    assert_eq!(comment.body, "Typo here");
+
+    reviews
+        .dismiss(1, 42)
+        .message("Outdated")
+        .priors(true)
+        .send(&client)
+        .await
+        .unwrap();
+    reviews.undismiss(1, 42).send(&client).await.unwrap();
+    reviews.delete(1, 42).send(&client).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(
+        requests[0].target,
+        "/api/v1/repos/owner/repo/pulls/1/reviews"
+    );
+    assert_eq!(
+        requests[0].body,
+        br#"{"body":"Nits","comments":[{"body":"Typo here","new_position":12,"old_position":0,"path":"src/lib.rs"},{"body":"Why remove this?","new_position":0,"old_position":3,"path":"src/old.rs"}]}"#
+    );
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/pulls/1/reviews/42"
+    );
+    assert_eq!(requests[1].body, br#"{"event":"APPROVED","body":"LGTM"}"#);
+    assert_eq!(requests[2].method, "GET");
+    assert_eq!(
+        requests[3].target,
+        "/api/v1/repos/owner/repo/pulls/1/reviews/42/comments"
+    );
+    assert_eq!(
+        requests[4].target,
+        "/api/v1/repos/owner/repo/pulls/1/reviews/42/comments/7"
+    );
+    assert_eq!(
+        requests[5].target,
+        "/api/v1/repos/owner/repo/pulls/1/reviews/42/dismissals"
+    );
+    assert_eq!(requests[5].body, br#"{"message":"Outdated","priors":true}"#);
+    assert_eq!(
+        requests[6].target,
+        "/api/v1/repos/owner/repo/pulls/1/reviews/42/undismissals"
+    );
+    assert_eq!(requests[7].method, "DELETE");
+    assert_eq!(
+        requests[7].target,
+        "/api/v1/repos/owner/repo/pulls/1/reviews/42"
+    );
+}
+
+#[tokio::test]
+async fn test_requested_reviewers() {
+    let server = MockServer::start(vec![
+        MockResponse::new(201, format!("[{}]", review(50, "REQUEST_REVIEW"))),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let pulls = client.pulls("owner", "repo");
+
+    let requested = pulls
+        .request_reviews(1)
+        .reviewer("alice")
+        .team("maintainers")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(requested[0].state, ReviewStateType::RequestReview);
+    pulls
+        .cancel_review_requests(1)
+        .reviewer("alice")
+        .send(&client)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(
+        requests[0].target,
+        "/api/v1/repos/owner/repo/pulls/1/requested_reviewers"
+    );
+    assert_eq!(
+        requests[0].body,
+        br#"{"reviewers":["alice"],"team_reviewers":["maintainers"]}"#
+    );
+    assert_eq!(requests[1].method, "DELETE");
+    assert_eq!(
+        requests[1].body,
+        br#"{"reviewers":["alice"],"team_reviewers":[]}"#
+    );
+}
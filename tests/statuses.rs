@@ -0,0 +1,75 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{
+    api::repos::statuses::list::StatusSort, model::statuses::CommitStatusState, Auth, Client,
+};
+
+const STATUS: &str = r#"{
+    "id": 1,
+    "status": "success",
+    "context": "ci/build",
+    "description": "Build succeeded",
+    "target_url": "https://ci.example.com/builds/42",
+    "created_at": "2024-01-01T00:00:00Z",
+    "updated_at": "2024-01-01T00:00:00Z",
+    "creator": {"id": 1, "login": "ci-bot"}
+}"#;
+
+#[tokio::test]
+async fn test_statuses() {
+    let server = MockServer::start(vec![
+        MockResponse::new(201, STATUS),
+        MockResponse::new(200, format!("[{STATUS}]")),
+        MockResponse::new(
+            200,
+            format!(
+                r#"{{"state": "failure", "sha": "abc", "total_count": 2, "statuses": [{STATUS}, {{"status": "failure", "context": "ci/test"}}]}}"#
+            ),
+        ),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let statuses = client.repos("owner", "repo").statuses();
+
+    let status = statuses
+        .create("abc", CommitStatusState::Success)
+        .context("ci/build")
+        .description("Build succeeded")
+        .target_url("https://ci.example.com/builds/42")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(status.status, CommitStatusState::Success);
+    assert_eq!(status.creator.unwrap().login, "ci-bot");
+
+    let list = statuses
+        .list("feature/x")
+        .state(CommitStatusState::Success)
+        .sort(StatusSort::RecentUpdate)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(list[0].context, "ci/build");
+
+    let combined = statuses.combined("main").send(&client).await.unwrap();
+    assert_eq!(combined.state, CommitStatusState::Failure);
+    assert_eq!(combined.total_count, 2);
+    assert_eq!(combined.statuses[1].status, CommitStatusState::Failure);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/statuses/abc");
+    assert_eq!(
+        requests[0].body,
+        br#"{"state":"success","target_url":"https://ci.example.com/builds/42","description":"Build succeeded","context":"ci/build"}"#
+    );
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/commits/feature%2Fx/statuses?sort=recentupdate&state=success"
+    );
+    assert_eq!(
+        requests[2].target,
+        "/api/v1/repos/owner/repo/commits/main/status"
+    );
+}
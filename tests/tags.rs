@@ -0,0 +1,128 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{Auth, Client};
+
+const TAG: &str = r#"{
+    "name": "v1.0.0",
+    "message": "First stable release",
+    "id": "tag1",
+    "commit": {"sha": "commit1", "url": "https://gitea.example.com/api/v1/repos/owner/repo/git/commits/commit1", "created": "2024-01-01T00:00:00Z"},
+    "zipball_url": "https://gitea.example.com/owner/repo/archive/v1.0.0.zip",
+    "tarball_url": "https://gitea.example.com/owner/repo/archive/v1.0.0.tar.gz"
+}"#;
+
+const PROTECTION: &str = r#"{
+    "id": 1,
+    "name_pattern": "v*",
+    "whitelist_usernames": ["release-bot"],
+    "whitelist_teams": ["maintainers"],
+    "created_at": "2024-01-01T00:00:00Z",
+    "updated_at": "2024-01-01T00:00:00Z"
+}"#;
+
+#[tokio::test]
+async fn test_tags() {
+    let server = MockServer::start(vec![
+        MockResponse::new(201, TAG),
+        MockResponse::new(201, TAG),
+        MockResponse::new(200, format!("[{TAG}]")),
+        MockResponse::new(200, TAG),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let tags = client.repos("owner", "repo").tags();
+
+    let tag = tags
+        .create("v1.0.0")
+        .message("First stable release")
+        .target("main")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(tag.name, "v1.0.0");
+    assert_eq!(tag.commit.sha, "commit1");
+    tags.create("nightly").send(&client).await.unwrap();
+
+    let list = tags.list().limit(10).send(&client).await.unwrap();
+    assert_eq!(list[0].message, "First stable release");
+    tags.get("release/v1").send(&client).await.unwrap();
+    tags.delete("release/v1").send(&client).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/tags");
+    assert_eq!(
+        requests[0].body,
+        br#"{"tag_name":"v1.0.0","message":"First stable release","target":"main"}"#
+    );
+    assert_eq!(requests[1].body, br#"{"tag_name":"nightly"}"#);
+    assert_eq!(requests[2].target, "/api/v1/repos/owner/repo/tags?limit=10");
+    assert_eq!(
+        requests[3].target,
+        "/api/v1/repos/owner/repo/tags/release/v1"
+    );
+    assert_eq!(requests[4].method, "DELETE");
+    assert_eq!(
+        requests[4].target,
+        "/api/v1/repos/owner/repo/tags/release/v1"
+    );
+}
+
+#[tokio::test]
+async fn test_tag_protections() {
+    let server = MockServer::start(vec![
+        MockResponse::new(201, PROTECTION),
+        MockResponse::new(200, format!("[{PROTECTION}]")),
+        MockResponse::new(200, PROTECTION),
+        MockResponse::new(200, PROTECTION),
+        MockResponse::new(204, ""),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let protections = client.repos("owner", "repo").tag_protections();
+
+    let protection = protections
+        .create("v*")
+        .whitelist_usernames(vec!["release-bot".to_string()])
+        .whitelist_teams(vec!["maintainers".to_string()])
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(protection.name_pattern, "v*");
+    assert_eq!(protection.whitelist_usernames, vec!["release-bot"]);
+
+    let list = protections.list().send(&client).await.unwrap();
+    assert_eq!(list[0].whitelist_teams, vec!["maintainers"]);
+    protections.get(1).send(&client).await.unwrap();
+    protections
+        .edit(1)
+        .whitelist_usernames(Vec::<String>::new())
+        .send(&client)
+        .await
+        .unwrap();
+    protections.delete(1).send(&client).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].target,
+        "/api/v1/repos/owner/repo/tag_protections"
+    );
+    assert_eq!(
+        requests[0].body,
+        br#"{"name_pattern":"v*","whitelist_usernames":["release-bot"],"whitelist_teams":["maintainers"]}"#
+    );
+    assert_eq!(requests[1].method, "GET");
+    assert_eq!(
+        requests[2].target,
+        "/api/v1/repos/owner/repo/tag_protections/1"
+    );
+    assert_eq!(requests[3].method, "PATCH");
+    assert_eq!(requests[3].body, br#"{"whitelist_usernames":[]}"#);
+    assert_eq!(requests[4].method, "DELETE");
+    assert_eq!(
+        requests[4].target,
+        "/api/v1/repos/owner/repo/tag_protections/1"
+    );
+}
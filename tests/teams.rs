@@ -0,0 +1,173 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{
+    model::team::{Permission, RepoUnit},
+    pagination::Paginated,
+    Auth, Client,
+};
+
+const TEAM: &str = r#"{
+    "can_create_org_repo": false,
+    "description": "",
+    "id": 7,
+    "includes_all_repositories": false,
+    "name": "core",
+    "organization": null,
+    "permission": "write",
+    "units": ["repo.code", "repo.pulls", "repo.something_new"],
+    "units_map": {"repo.code": "write", "repo.pulls": "read", "repo.something_new": "read"}
+}"#;
+
+#[tokio::test]
+async fn test_org_teams() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, format!("[{TEAM}]")),
+        MockResponse::new(200, format!(r#"{{"ok": true, "data": [{TEAM}]}}"#)),
+        MockResponse::new(201, TEAM),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let org = client.orgs("org");
+
+    let teams = org.list_teams().send(&client).await.unwrap();
+    let team = &teams[0];
+    assert_eq!(
+        team.units,
+        [
+            RepoUnit::Code,
+            RepoUnit::PullRequests,
+            RepoUnit::Other("repo.something_new".to_string())
+        ]
+    );
+    assert_eq!(team.units_map[&RepoUnit::Code], Permission::Write);
+    assert_eq!(team.units_map[&RepoUnit::PullRequests], Permission::Read);
+    assert_eq!(
+        team.units_map[&RepoUnit::from("repo.something_new")],
+        Permission::Read
+    );
+    // Units this crate doesn't know about are sent back to Gitea unchanged.
+    let json = serde_json::to_value(team).unwrap();
+    assert_eq!(json["units"][2], "repo.something_new");
+    assert_eq!(json["units_map"]["repo.something_new"], "read");
+    assert_eq!(json["units_map"]["repo.code"], "write");
+
+    let found = org
+        .search_teams("co")
+        .include_desc(true)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(found[0].name, "core");
+
+    let created = org
+        .create_team("core")
+        .units(vec![RepoUnit::Code])
+        .units_map(HashMap::from([(RepoUnit::Code, Permission::Write)]))
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(created.id, 7);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/orgs/org/teams");
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/orgs/org/teams/search?q=co&include_desc=true"
+    );
+    assert_eq!(requests[2].method, "POST");
+    assert_eq!(
+        requests[2].body,
+        br#"{"name":"core","units":["repo.code"],"units_map":{"repo.code":"write"}}"#
+    );
+}
+
+#[tokio::test]
+async fn test_search_all_teams() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, format!(r#"{{"ok": true, "data": [{TEAM}, {TEAM}]}}"#))
+            .header("x-total-count", "3"),
+        MockResponse::new(200, format!(r#"{{"ok": true, "data": [{TEAM}]}}"#))
+            .header("x-total-count", "3"),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let teams = client
+        .orgs("org")
+        .search_teams("co")
+        .limit(2)
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert_eq!(teams.len(), 3);
+    let requests = server.requests();
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/orgs/org/teams/search?q=co&page=2&limit=2"
+    );
+}
+
+#[tokio::test]
+async fn test_team_members_and_repos() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, TEAM),
+        MockResponse::new(200, TEAM),
+        MockResponse::new(204, ""),
+        MockResponse::new(200, r#"[{"id": 1, "login": "alice"}]"#),
+        MockResponse::new(200, r#"{"id": 1, "login": "alice"}"#),
+        MockResponse::new(404, ""),
+        MockResponse::new(204, ""),
+        MockResponse::new(204, ""),
+        MockResponse::new(204, ""),
+        MockResponse::new(204, ""),
+        MockResponse::new(200, format!("[{TEAM}]")),
+        MockResponse::new(200, format!("[{TEAM}]")),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let team = client.teams(7);
+
+    assert_eq!(team.get().send(&client).await.unwrap().name, "core");
+    team.edit()
+        .permission(Permission::Read)
+        .send(&client)
+        .await
+        .unwrap();
+    team.delete().send(&client).await.unwrap();
+    let members = team.list_members().send(&client).await.unwrap();
+    assert_eq!(members[0].login, "alice");
+    assert!(team.is_member("alice").send(&client).await.unwrap());
+    assert!(!team.is_member("bob").send(&client).await.unwrap());
+    team.add_member("bob").send(&client).await.unwrap();
+    team.remove_member("bob").send(&client).await.unwrap();
+    team.add_repo("org", "repo").send(&client).await.unwrap();
+    team.remove_repo("org", "repo").send(&client).await.unwrap();
+    let repo_teams = client
+        .repos("org", "repo")
+        .list_teams()
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(repo_teams[0].id, 7);
+    let user_teams = client.user().list_teams().send(&client).await.unwrap();
+    assert_eq!(user_teams[0].id, 7);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/teams/7");
+    assert_eq!(requests[1].method, "PATCH");
+    assert_eq!(requests[1].body, br#"{"permission":"read"}"#);
+    assert_eq!(requests[2].method, "DELETE");
+    assert_eq!(requests[3].target, "/api/v1/teams/7/members");
+    assert_eq!(requests[4].target, "/api/v1/teams/7/members/alice");
+    assert_eq!(requests[6].method, "PUT");
+    assert_eq!(requests[6].target, "/api/v1/teams/7/members/bob");
+    assert_eq!(requests[7].method, "DELETE");
+    assert_eq!(requests[8].method, "PUT");
+    assert_eq!(requests[8].target, "/api/v1/teams/7/repos/org/repo");
+    assert_eq!(requests[9].method, "DELETE");
+    assert_eq!(requests[10].target, "/api/v1/repos/org/repo/teams");
+    assert_eq!(requests[11].target, "/api/v1/user/teams");
+}
@@ -0,0 +1,184 @@
+mod common;
+
+use common::MockServer;
+use gitea_sdk::{
+    model::{issues::Issue, Timestamp},
+    Auth, Client,
+};
+
+#[test]
+fn test_keeps_timestamps_as_sent() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "created_at": "2024-05-01T12:30:00+02:00",
+        "updated_at": "2024-05-02T08:00:00Z",
+        "due_date": "2024-06-01",
+    }))
+    .unwrap();
+    assert_eq!(issue.created_at, "2024-05-01T12:30:00+02:00");
+    assert_eq!(issue.updated_at.as_str(), "2024-05-02T08:00:00Z");
+    assert_eq!(issue.due_date.as_ref().unwrap().to_string(), "2024-06-01");
+
+    let json = serde_json::to_value(&issue).unwrap();
+    assert_eq!(json["created_at"], "2024-05-01T12:30:00+02:00");
+}
+
+#[test]
+fn test_used_as_string() {
+    let issue: Issue =
+        serde_json::from_value(serde_json::json!({ "created_at": "2024-05-01T12:30:00Z" }))
+            .unwrap();
+    assert_eq!(issue.created_at, "2024-05-01T12:30:00Z".to_string());
+    assert!("2024-05-01T12:30:00Z" == issue.created_at);
+    assert!(issue.created_at.starts_with("2024-05"));
+    let date: &str = &issue.created_at;
+    assert_eq!(date.len(), 20);
+    assert_eq!(String::from(issue.created_at), "2024-05-01T12:30:00Z");
+}
+
+#[test]
+fn test_zero_dates() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "created_at": "",
+        "updated_at": null,
+        "closed_at": "0001-01-01T00:00:00Z",
+        "due_date": "1970-01-01T01:00:00+01:00",
+    }))
+    .unwrap();
+    assert_eq!(issue.created_at, Timestamp::default());
+    assert_eq!(issue.updated_at, Timestamp::default());
+    assert!(issue.created_at.is_zero());
+    assert_eq!(issue.closed_at, None);
+    assert_eq!(issue.due_date, None);
+
+    let issue: Issue = serde_json::from_value(serde_json::json!({ "closed_at": "" })).unwrap();
+    assert_eq!(issue.closed_at, None);
+}
+
+#[test]
+fn test_is_zero() {
+    for zero in [
+        "",
+        "0001-01-01T00:00:00Z",
+        "0001-01-01T00:17:30+00:17",
+        "0000-12-31T23:00:00-01:00",
+        "1970-01-01",
+        "1970-01-01T00:00:00Z",
+        "1970-01-01T00:00:00.000Z",
+        "1970-01-01T08:00:00+08:00",
+        "1969-12-31T19:00:00-05:00",
+    ] {
+        assert!(Timestamp::from(zero).is_zero(), "{zero}");
+    }
+    for date in [
+        "1970-01-01T00:00:01Z",
+        "1970-01-01T00:00:00+01:00",
+        "1969-12-31T23:59:59Z",
+        "2024-05-01T12:30:00+02:00",
+    ] {
+        assert!(!Timestamp::from(date).is_zero(), "{date}");
+    }
+}
+
+#[test]
+fn test_accepts_invalid_timestamps() {
+    // Deserializing doesn't depend on the chrono feature, so invalid values are kept as is.
+    let issue =
+        serde_json::from_value::<Issue>(serde_json::json!({ "created_at": "yesterday" })).unwrap();
+    assert_eq!(issue.created_at, "yesterday");
+    #[cfg(feature = "chrono")]
+    assert_eq!(issue.created_at.as_datetime(), None);
+}
+
+#[tokio::test]
+async fn test_string_filters() {
+    let server = MockServer::start(vec![]).await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let _ = client
+        .issues("owner", "repo")
+        .list()
+        .since("2024-01-02T03:04:05Z")
+        .send(&client)
+        .await;
+
+    assert_eq!(
+        server.requests()[0].target,
+        "/api/v1/repos/owner/repo/issues?since=2024-01-02T03%3A04%3A05Z"
+    );
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_feature {
+    use chrono::{FixedOffset, TimeZone, Utc};
+    use gitea_sdk::{model::issues::Issue, Auth, Client};
+
+    use crate::common::MockServer;
+
+    #[test]
+    fn test_parses_timestamps() {
+        let issue: Issue = serde_json::from_value(serde_json::json!({
+            "created_at": "2024-05-01T12:30:00+02:00",
+            "updated_at": "2024-05-02T08:00:00",
+            "closed_at": "2024-05-03T09:15:00.123Z",
+            "due_date": "2024-06-01",
+        }))
+        .unwrap();
+        assert_eq!(
+            issue.created_at.as_datetime(),
+            Some(Utc.with_ymd_and_hms(2024, 5, 1, 10, 30, 0).unwrap())
+        );
+        assert_eq!(
+            issue.updated_at.as_datetime(),
+            Some(Utc.with_ymd_and_hms(2024, 5, 2, 8, 0, 0).unwrap())
+        );
+        assert_eq!(
+            issue
+                .closed_at
+                .unwrap()
+                .as_datetime()
+                .unwrap()
+                .timestamp_millis(),
+            Utc.with_ymd_and_hms(2024, 5, 3, 9, 15, 0)
+                .unwrap()
+                .timestamp_millis()
+                + 123
+        );
+        assert_eq!(
+            issue.due_date.unwrap().as_datetime(),
+            Some(Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_zero_dates_have_no_datetime() {
+        let issue: Issue = serde_json::from_value(serde_json::json!({
+            "created_at": "0001-01-01T00:00:00Z",
+            "updated_at": "",
+        }))
+        .unwrap();
+        assert_eq!(issue.created_at.as_datetime(), None);
+        assert_eq!(issue.updated_at.as_datetime(), None);
+    }
+
+    #[tokio::test]
+    async fn test_typed_filters() {
+        let server = MockServer::start(vec![]).await;
+        let client = Client::new(&server.url, Auth::Token("token"));
+        let since = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        let before = FixedOffset::east_opt(2 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2024, 2, 1, 0, 0, 0)
+            .unwrap();
+        let _ = client
+            .issues("owner", "repo")
+            .list()
+            .since(since)
+            .before(before)
+            .send(&client)
+            .await;
+
+        assert_eq!(
+            server.requests()[0].target,
+            "/api/v1/repos/owner/repo/issues?since=2024-01-02T03%3A04%3A05Z&before=2024-02-01T00%3A00%3A00%2B02%3A00"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use gitea_sdk::{model::activities::ActivityOpType, pagination::Paginated, Auth, Client};
+
+#[tokio::test]
+async fn test_repo_topics() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, r#"{"topics": ["rust", "sdk"]}"#),
+        MockResponse::new(204, ""),
+        MockResponse::new(204, ""),
+        MockResponse::new(204, ""),
+        MockResponse::new(
+            200,
+            r#"{"topics": [{"id": 1, "topic_name": "rust", "repo_count": 3, "created": "2024-01-01T00:00:00Z", "updated": "2024-01-01T00:00:00Z"}]}"#,
+        ),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let topics = client.repos("owner", "repo").topics();
+
+    assert_eq!(topics.list().send(&client).await.unwrap(), ["rust", "sdk"]);
+    topics.replace(["rust", "sdk"]).send(&client).await.unwrap();
+    topics.add("gitea").send(&client).await.unwrap();
+    topics.delete("gitea").send(&client).await.unwrap();
+    let found = client
+        .search()
+        .topics()
+        .query("ru")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(found[0].topic_name, "rust");
+    assert_eq!(found[0].repo_count, 3);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/topics");
+    assert_eq!(requests[1].method, "PUT");
+    assert_eq!(requests[1].body, br#"{"topics":["rust","sdk"]}"#);
+    assert_eq!(requests[2].method, "PUT");
+    assert_eq!(requests[2].target, "/api/v1/repos/owner/repo/topics/gitea");
+    assert_eq!(requests[3].method, "DELETE");
+    assert_eq!(requests[4].target, "/api/v1/topics/search?q=ru");
+}
+
+#[tokio::test]
+async fn test_list_all_topics() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, r#"{"topics": ["a", "b"]}"#).header("x-total-count", "3"),
+        MockResponse::new(200, r#"{"topics": ["c"]}"#).header("x-total-count", "3"),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+
+    let topics = client
+        .repos("owner", "repo")
+        .topics()
+        .list()
+        .limit(2)
+        .send_all(&client)
+        .await
+        .unwrap();
+    assert_eq!(topics, ["a", "b", "c"]);
+}
+
+#[tokio::test]
+async fn test_languages_and_activities() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, r#"{"Rust": 12345, "Shell": 67}"#),
+        MockResponse::new(
+            200,
+            r#"[{"id": 5, "op_type": "commit_repo", "ref_name": "refs/heads/main", "act_user": {"id": 1, "login": "alice"}, "repo_id": 2, "created": "2024-06-01T12:00:00Z"},
+                {"id": 6, "op_type": "some_future_op"}]"#,
+        ),
+    ])
+    .await;
+    let client = Client::new(&server.url, Auth::Token("token"));
+    let repo = client.repos("owner", "repo");
+
+    let languages = repo.languages().send(&client).await.unwrap();
+    assert_eq!(languages["Rust"], 12345);
+    assert_eq!(languages["Shell"], 67);
+
+    let activities = repo
+        .activity_feeds()
+        .date("2024-06-01")
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(activities[0].op_type, ActivityOpType::CommitRepo);
+    assert_eq!(activities[0].ref_name, "refs/heads/main");
+    assert_eq!(activities[0].act_user.as_ref().unwrap().login, "alice");
+    assert_eq!(activities[1].op_type, ActivityOpType::Unknown);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].target, "/api/v1/repos/owner/repo/languages");
+    assert_eq!(
+        requests[1].target,
+        "/api/v1/repos/owner/repo/activities/feeds?date=2024-06-01"
+    );
+}
@@ -0,0 +1,147 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use gitea_sdk::{
+    error::{TeatimeError, TeatimeErrorKind},
+    retry::RetryPolicy,
+    transport::Transport,
+    Auth, Client,
+};
+use reqwest::{Request, Response, StatusCode};
+
+fn response(status: u16, body: &'static str) -> Response {
+    http::Response::builder()
+        .status(status)
+        .body(body)
+        .unwrap()
+        .into()
+}
+
+/// A transport that records the requests it receives and answers them from a script.
+#[derive(Clone, Default)]
+struct FakeTransport {
+    requests: Arc<Mutex<Vec<Request>>>,
+    responses: Arc<Mutex<Vec<gitea_sdk::error::Result<Response>>>>,
+}
+
+impl FakeTransport {
+    fn new(responses: Vec<gitea_sdk::error::Result<Response>>) -> Self {
+        Self {
+            requests: Arc::default(),
+            responses: Arc::new(Mutex::new(responses.into_iter().rev().collect())),
+        }
+    }
+}
+
+impl Transport for FakeTransport {
+    fn send(
+        &self,
+        request: Request,
+    ) -> futures_core::future::BoxFuture<'_, gitea_sdk::error::Result<Response>> {
+        self.requests.lock().unwrap().push(request);
+        let response = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop()
+            .unwrap_or_else(|| Ok(response(200, "{}")));
+        Box::pin(async move { response })
+    }
+}
+
+#[tokio::test]
+async fn test_custom_transport() {
+    let transport = FakeTransport::new(vec![Ok(response(200, r#"{"name": "repo"}"#))]);
+    let client = Client::builder("https://gitea.example.com/gitea")
+        .auth(Auth::Token("secret"))
+        .user_agent("my-bot")
+        .transport(transport.clone())
+        .build()
+        .unwrap();
+
+    let repo = client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(repo.name, "repo");
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request.method(), "GET");
+    assert_eq!(
+        request.url().as_str(),
+        "https://gitea.example.com/gitea/api/v1/repos/owner/repo"
+    );
+    // The client's default headers are part of the request the transport receives.
+    assert_eq!(request.headers()["authorization"], "token secret");
+    assert_eq!(request.headers()["user-agent"], "my-bot");
+    assert_eq!(request.headers()["accept"], "application/json");
+}
+
+#[tokio::test]
+async fn test_error_responses() {
+    let transport = FakeTransport::new(vec![Ok(response(
+        403,
+        r#"{"message": "token does not have required scope"}"#,
+    ))]);
+    let client = Client::builder("https://gitea.example.com")
+        .transport(transport)
+        .build()
+        .unwrap();
+
+    let err = client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind, TeatimeErrorKind::Forbidden);
+    assert_eq!(err.status_code, Some(StatusCode::FORBIDDEN));
+    assert_eq!(err.message, "token does not have required scope");
+}
+
+#[tokio::test]
+async fn test_transport_errors_are_retried() {
+    let transport = FakeTransport::new(vec![
+        Err(TeatimeError::new(TeatimeErrorKind::Connect, "refused")),
+        Err(TeatimeError::new(TeatimeErrorKind::Timeout, "timed out")),
+        Ok(response(200, r#"{"name": "repo"}"#)),
+    ]);
+    let client = Client::builder("https://gitea.example.com")
+        .transport(transport.clone())
+        .retry_policy(RetryPolicy::new().initial_backoff(Duration::from_millis(1)))
+        .build()
+        .unwrap();
+
+    let repo = client
+        .repos("owner", "repo")
+        .get()
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(repo.name, "repo");
+    assert_eq!(transport.requests.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_closure_transport() {
+    let client = Client::builder("https://gitea.example.com")
+        .transport(|request: Request| async move {
+            assert_eq!(request.url().query(), Some("page=2"));
+            Ok(response(200, r#"[{"name": "a"}, {"name": "b"}]"#))
+        })
+        .build()
+        .unwrap();
+
+    let forks = client
+        .repos("owner", "repo")
+        .get_forks()
+        .page(2)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(forks.len(), 2);
+}
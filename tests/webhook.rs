@@ -0,0 +1,166 @@
+use gitea_sdk::webhook::{self, Event, IssueAction, RefType, ReleaseAction};
+
+const PUSH: &str = r#"{"ref":"refs/heads/main"}"#;
+// echo -n '{"ref":"refs/heads/main"}' | openssl dgst -sha256 -hmac secret
+const PUSH_SIGNATURE: &str = "d8f89f0618acd61fe621aa4e64078c0e2bca15d0b578b7f3eb734f55883c5320";
+
+#[test]
+fn test_verify_signature() {
+    let body = PUSH.as_bytes();
+    assert_eq!(webhook::signature("secret", body), PUSH_SIGNATURE);
+    assert!(webhook::verify_signature("secret", body, PUSH_SIGNATURE));
+    assert!(webhook::verify_signature(
+        "secret",
+        body,
+        &format!("sha256={PUSH_SIGNATURE}")
+    ));
+    assert!(webhook::verify_signature(
+        "secret",
+        body,
+        &PUSH_SIGNATURE.to_uppercase()
+    ));
+
+    assert!(!webhook::verify_signature("wrong", body, PUSH_SIGNATURE));
+    assert!(!webhook::verify_signature(
+        "secret",
+        br#"{"ref":"refs/heads/evil"}"#,
+        PUSH_SIGNATURE
+    ));
+    assert!(!webhook::verify_signature("secret", body, ""));
+    assert!(!webhook::verify_signature("secret", body, "not hex"));
+    assert!(!webhook::verify_signature(
+        "secret",
+        body,
+        &PUSH_SIGNATURE[..62]
+    ));
+}
+
+#[test]
+fn test_push_event() {
+    let body = r#"{
+        "ref": "refs/heads/main",
+        "before": "0000000000000000000000000000000000000000",
+        "after": "abc",
+        "commits": [{"id": "abc", "message": "Initial commit", "author": {"name": "Alice", "email": "alice@example.com", "username": "alice"}}],
+        "total_commits": 1,
+        "repository": {"id": 1, "full_name": "owner/repo"},
+        "pusher": {"login": "alice"},
+        "sender": {"login": "alice"}
+    }"#;
+    let Event::Push(push) = Event::parse("push", body.as_bytes()).unwrap() else {
+        panic!("expected a push event");
+    };
+    assert_eq!(push.r#ref, "refs/heads/main");
+    assert_eq!(push.commits[0].message, "Initial commit");
+    assert_eq!(push.commits[0].author.username, "alice");
+    assert!(push.head_commit.is_none());
+    assert_eq!(push.repository.full_name, "owner/repo");
+    assert_eq!(push.pusher.login, "alice");
+}
+
+#[test]
+fn test_create_and_delete_events() {
+    let body = r#"{"ref": "v1.0.0", "ref_type": "tag", "sha": "abc", "repository": {"id": 1}}"#;
+    let Event::Create(create) = Event::parse("create", body.as_bytes()).unwrap() else {
+        panic!("expected a create event");
+    };
+    assert_eq!(create.ref_type, RefType::Tag);
+
+    let body = r#"{"ref": "feature", "ref_type": "branch", "pusher_type": "user"}"#;
+    let Event::Delete(delete) = Event::parse("delete", body.as_bytes()).unwrap() else {
+        panic!("expected a delete event");
+    };
+    assert_eq!(delete.r#ref, "feature");
+    assert_eq!(delete.ref_type, RefType::Branch);
+}
+
+#[test]
+fn test_issue_events() {
+    let body = r#"{
+        "action": "label_updated",
+        "number": 3,
+        "issue": {"number": 3, "title": "Bug", "labels": [{"id": 1, "name": "bug"}]},
+        "repository": {"id": 1},
+        "sender": {"login": "alice"}
+    }"#;
+    let Event::Issues(issue) = Event::parse("issues", body.as_bytes()).unwrap() else {
+        panic!("expected an issues event");
+    };
+    assert_eq!(issue.action, IssueAction::LabelUpdated);
+    assert_eq!(issue.issue.labels[0].name, "bug");
+
+    let body = r#"{
+        "action": "edited",
+        "issue": {"number": 3},
+        "comment": {"id": 7, "body": "Fixed"},
+        "changes": {"body": {"from": "Fixd"}},
+        "is_pull": false
+    }"#;
+    let Event::IssueComment(comment) = Event::parse("issue_comment", body.as_bytes()).unwrap()
+    else {
+        panic!("expected an issue comment event");
+    };
+    assert_eq!(comment.comment.body, "Fixed");
+    assert_eq!(comment.changes.unwrap().body.unwrap().from, "Fixd");
+    assert!(comment.pull_request.is_none());
+}
+
+#[test]
+fn test_pull_request_event() {
+    let branch =
+        r#"{"label": "main", "ref": "main", "repo": {"id": 1}, "repo_id": 1, "sha": "abc"}"#;
+    let body = format!(
+        r#"{{
+            "action": "reviewed",
+            "number": 5,
+            "pull_request": {{
+                "allow_maintainer_edit": false, "base": {branch}, "head": {branch}, "body": "",
+                "comments": 0, "created_at": "2024-01-01T00:00:00Z", "diff_url": "", "draft": false,
+                "html_url": "", "id": 9, "is_locked": false, "labels": [], "merge_base": "",
+                "mergeable": true, "merged": false, "number": 5, "patch_url": "", "pin_order": 0,
+                "state": "open", "title": "Add feature", "updated_at": "2024-01-01T00:00:00Z",
+                "url": "", "user": {{"login": "bob"}}
+            }},
+            "repository": {{"id": 1}},
+            "sender": {{"login": "alice"}},
+            "review": {{"type": "pull_request_review_approved", "content": "LGTM"}}
+        }}"#
+    );
+    let Event::PullRequest(pr) = Event::parse("pull_request_approved", body.as_bytes()).unwrap()
+    else {
+        panic!("expected a pull request event");
+    };
+    assert_eq!(pr.action, IssueAction::Reviewed);
+    assert_eq!(pr.pull_request.title, "Add feature");
+    assert_eq!(pr.review.unwrap().content, "LGTM");
+}
+
+#[test]
+fn test_release_event() {
+    let body = r#"{"action": "published", "release": {"id": 1, "tag_name": "v1.0.0"}}"#;
+    let Event::Release(release) = Event::parse("release", body.as_bytes()).unwrap() else {
+        panic!("expected a release event");
+    };
+    assert_eq!(release.action, ReleaseAction::Published);
+    assert_eq!(release.release.tag_name, "v1.0.0");
+}
+
+#[test]
+fn test_unknown_and_invalid_events() {
+    let Event::Unknown { event, payload } =
+        Event::parse("wiki", br#"{"action": "created"}"#).unwrap()
+    else {
+        panic!("expected an unknown event");
+    };
+    assert_eq!(event, "wiki");
+    assert_eq!(payload["action"], "created");
+
+    let err = Event::parse("push", b"not json").unwrap_err();
+    assert_eq!(err.kind, gitea_sdk::error::TeatimeErrorKind::Decode);
+    // Unknown actions don't fail the whole payload.
+    let Event::Issues(issue) = Event::parse("issues", br#"{"action": "teleported"}"#).unwrap()
+    else {
+        panic!("expected an issues event");
+    };
+    assert_eq!(issue.action, IssueAction::Unknown);
+}